@@ -22,15 +22,32 @@ fn setup_camera(mut commands: Commands) {
             scaling_mode: bevy::render::camera::ScalingMode::FixedVertical { viewport_height: 800.0 },
             ..OrthographicProjection::default_2d()
         }),
-    ));
+    )).with_children(|parent| {
+        // Sits in front of the world so the day-night tint covers every sprite;
+        // wide enough for ultrawide windows at the fixed 800 unit viewport height
+        parent.spawn((
+            Sprite {
+                color: Color::NONE,
+                custom_size: Some(Vec2::new(4000.0, 1000.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, 900.0),
+            crate::components::DayNightOverlay::default(),
+        ));
+    });
 }
 
+// The camera, kept apart from the player it follows
+type FollowingCamera = (With<MainCamera>, Without<crate::components::Player>);
+
+// The view stops at the edge of the map instead of showing the void beyond it
 fn camera_follow(
+    bounds: Option<Res<crate::components::WorldBounds>>,
     player_query: Query<&Transform, With<crate::components::Player>>,
-    mut camera_query: Query<&mut Transform, (With<MainCamera>, Without<crate::components::Player>)>,
+    mut camera_query: Query<(&mut Transform, &Projection), FollowingCamera>,
 ) {
     if let Ok(player_transform) = player_query.single() {
-        if let Ok(mut camera_transform) = camera_query.single_mut() {
+        if let Ok((mut camera_transform, projection)) = camera_query.single_mut() {
             let target_position = Vec3::new(
                 player_transform.translation.x,
                 player_transform.translation.y,
@@ -38,6 +55,14 @@ fn camera_follow(
             );
             
             camera_transform.translation = camera_transform.translation.lerp(target_position, 0.1);
+
+            // Clamped after following too, so resizing the window can't reveal the edge
+            if let (Some(bounds), Projection::Orthographic(orthographic)) = (bounds, projection) {
+                let half_view = orthographic.area.half_size();
+                let clamped = bounds.clamp_view(camera_transform.translation.truncate(), half_view);
+                camera_transform.translation.x = clamped.x;
+                camera_transform.translation.y = clamped.y;
+            }
         }
     }
-}
\ No newline at end of file
+}
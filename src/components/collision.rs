@@ -0,0 +1,153 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+// Axis-aligned box relative to an entity's position, e.g. a tree trunk's footprint
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Collider {
+    pub half_size: Vec2,
+    pub offset: Vec2,
+}
+
+impl Collider {
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            half_size: size / 2.0,
+            offset: Vec2::ZERO,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn rect(&self, position: Vec2) -> Rect {
+        Rect::from_center_half_size(position + self.offset, self.half_size)
+    }
+}
+
+// Static world geometry that the player and animals can't walk through. Water only stops
+// those that can't swim.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Obstacle {
+    pub shape: Collider,
+    pub water: bool,
+}
+
+impl Obstacle {
+    pub fn solid(size: Vec2) -> Self {
+        Self {
+            shape: Collider::new(size),
+            water: false,
+        }
+    }
+
+    pub fn water(size: Vec2) -> Self {
+        Self {
+            shape: Collider::new(size),
+            water: true,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.shape = self.shape.with_offset(offset);
+        self
+    }
+}
+
+// Small gap left between a body and the obstacle it stopped against
+const CONTACT_GAP: f32 = 0.01;
+
+// Obstacles never move, so reading their GlobalTransform keeps this usable next to
+// queries that move the player and animals
+#[derive(SystemParam)]
+pub struct Obstacles<'w, 's> {
+    query: Query<'w, 's, (&'static GlobalTransform, &'static Obstacle)>,
+}
+
+impl Obstacles<'_, '_> {
+    // Where a body at `position` ends up after trying to move by `delta`. Each axis is
+    // resolved on its own, so a body pushing diagonally into a wall slides along it.
+    // Obstacles the body already overlaps are ignored so it can always walk out of them.
+    pub fn resolve(&self, position: Vec2, delta: Vec2, body: &Collider, can_swim: bool) -> Vec2 {
+        let start = body.rect(position);
+        let blockers: Vec<Rect> = self
+            .query
+            .iter()
+            .filter(|(_, obstacle)| !(obstacle.water && can_swim))
+            .map(|(transform, obstacle)| obstacle.shape.rect(transform.translation().truncate()))
+            .filter(|rect| !overlaps(*rect, start))
+            .collect();
+
+        let mut resolved = position;
+
+        resolved.x += delta.x;
+        for blocker in &blockers {
+            if overlaps(*blocker, body.rect(resolved)) {
+                resolved.x = if delta.x > 0.0 {
+                    blocker.min.x - body.half_size.x - body.offset.x - CONTACT_GAP
+                } else {
+                    blocker.max.x + body.half_size.x - body.offset.x + CONTACT_GAP
+                };
+            }
+        }
+
+        resolved.y += delta.y;
+        for blocker in &blockers {
+            if overlaps(*blocker, body.rect(resolved)) {
+                resolved.y = if delta.y > 0.0 {
+                    blocker.min.y - body.half_size.y - body.offset.y - CONTACT_GAP
+                } else {
+                    blocker.max.y + body.half_size.y - body.offset.y + CONTACT_GAP
+                };
+            }
+        }
+
+        resolved
+    }
+}
+
+// Touching edges don't count, so a body resting against an obstacle can slide along it
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.min.x < b.max.x && a.max.x > b.min.x && a.min.y < b.max.y && a.max.y > b.min.y
+}
+
+// Edges of the generated map. The player, the animals and the camera view all stay inside.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct WorldBounds {
+    pub rect: Rect,
+}
+
+impl WorldBounds {
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            rect: Rect::from_center_size(Vec2::ZERO, size),
+        }
+    }
+
+    // Nearest position at which the body's collider lies fully on the map
+    pub fn clamp_body(&self, position: Vec2, body: &Collider) -> Vec2 {
+        let center = self.clamp_box(position + body.offset, body.half_size);
+        center - body.offset
+    }
+
+    // Nearest camera centre at which a view of this half size shows nothing beyond the map
+    pub fn clamp_view(&self, center: Vec2, half_view: Vec2) -> Vec2 {
+        self.clamp_box(center, half_view)
+    }
+
+    // A box wider or taller than the map is centred on it along that axis
+    fn clamp_box(&self, center: Vec2, half_size: Vec2) -> Vec2 {
+        let clamp_axis = |value: f32, min: f32, max: f32, half: f32| {
+            if max - min <= half * 2.0 {
+                (min + max) / 2.0
+            } else {
+                value.clamp(min + half, max - half)
+            }
+        };
+        Vec2::new(
+            clamp_axis(center.x, self.rect.min.x, self.rect.max.x, half_size.x),
+            clamp_axis(center.y, self.rect.min.y, self.rect.max.y, half_size.y),
+        )
+    }
+}
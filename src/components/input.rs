@@ -0,0 +1,329 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+
+// Everything the player can do, independent of which key does it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Run,
+    Interact,
+    PlayWithAnimal,
+    Collect,
+    Eat,
+    UseItem,
+    Sleep,
+    ToggleInventory,
+    // Hotbar slot, counted from 1 like the number keys
+    SelectSlot(u8),
+    SelectPrevious,
+    SelectNext,
+    ToggleQuestJournal,
+    CycleTrackedQuest,
+    ClaimMailbox,
+    ToggleTimePause,
+    TimeSlower,
+    TimeFaster,
+    DebugTimeSpeed,
+    QuickSave,
+    Pause,
+    MenuUp,
+    MenuDown,
+    Confirm,
+}
+
+// Bindings only apply while their context is active. Global is always active;
+// Inventory sits on top of Gameplay while the inventory is open and takes over its keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+pub enum InputContext {
+    Global,
+    Gameplay,
+    Inventory,
+    Menu,
+}
+
+// Layout of config/bindings.ron: key and gamepad button names per action, grouped by context
+pub type BindingsFile = BTreeMap<InputContext, BTreeMap<InputAction, Vec<String>>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Button(GamepadButton),
+}
+
+impl InputBinding {
+    pub fn from_name(name: &str) -> Option<Self> {
+        key_from_name(name)
+            .map(InputBinding::Key)
+            .or_else(|| button_from_name(name).map(InputBinding::Button))
+    }
+
+    pub fn device(&self) -> InputDevice {
+        match self {
+            InputBinding::Key(_) => InputDevice::Keyboard,
+            InputBinding::Button(_) => InputDevice::Gamepad,
+        }
+    }
+
+    // Short text shown in on-screen prompts
+    pub fn label(&self) -> String {
+        match self {
+            InputBinding::Key(key) => key_label(*key),
+            InputBinding::Button(button) => button_label(*button).to_string(),
+        }
+    }
+}
+
+// Whichever device the player touched last; prompts show its buttons
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputDevice {
+    #[default]
+    Keyboard,
+    Gamepad,
+}
+
+// Bindings per action within one context
+type ContextBindings = Vec<(InputAction, Vec<InputBinding>)>;
+
+#[derive(Resource)]
+pub struct InputBindings {
+    contexts: Vec<(InputContext, ContextBindings)>,
+}
+
+impl InputBindings {
+    pub fn new(file: BindingsFile) -> Self {
+        let contexts = file
+            .into_iter()
+            .map(|(context, actions)| {
+                let actions = actions
+                    .into_iter()
+                    .map(|(action, names)| {
+                        let inputs = names
+                            .iter()
+                            .filter_map(|name| {
+                                let input = InputBinding::from_name(name);
+                                if input.is_none() {
+                                    println!("按键绑定 {:?} 中的按键 {} 无法识别，已忽略", action, name);
+                                }
+                                input
+                            })
+                            .collect();
+                        (action, inputs)
+                    })
+                    .collect();
+                (context, actions)
+            })
+            .collect();
+        Self { contexts }
+    }
+
+    pub fn actions(&self, context: InputContext) -> impl Iterator<Item = &(InputAction, Vec<InputBinding>)> {
+        self.contexts
+            .iter()
+            .filter(move |(c, _)| *c == context)
+            .flat_map(|(_, actions)| actions.iter())
+    }
+
+    pub fn inputs(&self, context: InputContext) -> impl Iterator<Item = InputBinding> + '_ {
+        self.actions(context).flat_map(|(_, inputs)| inputs.iter().copied())
+    }
+
+    // Label of the first binding on the given device, falling back to any binding
+    pub fn label(&self, context: InputContext, action: InputAction, device: InputDevice) -> String {
+        let inputs: Vec<InputBinding> = self
+            .actions(context)
+            .filter(|(bound, _)| *bound == action)
+            .flat_map(|(_, inputs)| inputs.iter().copied())
+            .collect();
+        inputs
+            .iter()
+            .find(|input| input.device() == device)
+            .or(inputs.first())
+            .map(InputBinding::label)
+            .unwrap_or_else(|| "?".to_string())
+    }
+
+    // Two actions that can be active at the same time must not share a key: within one
+    // context, or between Global and any other context. Inventory overriding Gameplay is intended.
+    pub fn conflicts(&self) -> Vec<BindingConflict> {
+        let mut conflicts = Vec::new();
+        let bindings: Vec<(InputContext, InputAction, InputBinding)> = self
+            .contexts
+            .iter()
+            .flat_map(|(context, actions)| {
+                actions.iter().flat_map(move |(action, inputs)| inputs.iter().map(move |input| (*context, *action, *input)))
+            })
+            .collect();
+
+        for (i, &(context_a, action_a, input_a)) in bindings.iter().enumerate() {
+            for &(context_b, action_b, input_b) in &bindings[i + 1..] {
+                let overlapping = context_a == context_b
+                    || context_a == InputContext::Global
+                    || context_b == InputContext::Global;
+                if input_a == input_b && action_a != action_b && overlapping {
+                    conflicts.push(BindingConflict {
+                        input: input_a,
+                        first: (context_a, action_a),
+                        second: (context_b, action_b),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+pub struct BindingConflict {
+    pub input: InputBinding,
+    pub first: (InputContext, InputAction),
+    pub second: (InputContext, InputAction),
+}
+
+// What the player asked for this frame, resolved from the bindings of the active contexts
+#[derive(Resource, Default)]
+pub struct ActionState {
+    pressed: HashSet<InputAction>,
+    just_pressed: HashSet<InputAction>,
+    // Analog movement from the left stick, already past the dead zone
+    move_axis: Vec2,
+}
+
+impl ActionState {
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    pub fn press(&mut self, action: InputAction, just_pressed: bool) {
+        self.pressed.insert(action);
+        if just_pressed {
+            self.just_pressed.insert(action);
+        }
+    }
+
+    pub fn set_move_axis(&mut self, axis: Vec2) {
+        self.move_axis = axis;
+    }
+
+    pub fn clear(&mut self) {
+        self.pressed.clear();
+        self.just_pressed.clear();
+        self.move_axis = Vec2::ZERO;
+    }
+
+    // Movement direction scaled by how far the stick is pushed; digital input always counts
+    // as fully pushed. Never longer than 1.
+    pub fn movement(&self) -> Vec2 {
+        let mut movement = Vec2::ZERO;
+        if self.pressed(InputAction::MoveUp) {
+            movement.y += 1.0;
+        }
+        if self.pressed(InputAction::MoveDown) {
+            movement.y -= 1.0;
+        }
+        if self.pressed(InputAction::MoveLeft) {
+            movement.x -= 1.0;
+        }
+        if self.pressed(InputAction::MoveRight) {
+            movement.x += 1.0;
+        }
+        (movement.normalize_or_zero() + self.move_axis).clamp_length_max(1.0)
+    }
+}
+
+// Key names as written in config/bindings.ron, matching Bevy's KeyCode variants
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+    ("KeyA", KeyCode::KeyA), ("KeyB", KeyCode::KeyB), ("KeyC", KeyCode::KeyC), ("KeyD", KeyCode::KeyD),
+    ("KeyE", KeyCode::KeyE), ("KeyF", KeyCode::KeyF), ("KeyG", KeyCode::KeyG), ("KeyH", KeyCode::KeyH),
+    ("KeyI", KeyCode::KeyI), ("KeyJ", KeyCode::KeyJ), ("KeyK", KeyCode::KeyK), ("KeyL", KeyCode::KeyL),
+    ("KeyM", KeyCode::KeyM), ("KeyN", KeyCode::KeyN), ("KeyO", KeyCode::KeyO), ("KeyP", KeyCode::KeyP),
+    ("KeyQ", KeyCode::KeyQ), ("KeyR", KeyCode::KeyR), ("KeyS", KeyCode::KeyS), ("KeyT", KeyCode::KeyT),
+    ("KeyU", KeyCode::KeyU), ("KeyV", KeyCode::KeyV), ("KeyW", KeyCode::KeyW), ("KeyX", KeyCode::KeyX),
+    ("KeyY", KeyCode::KeyY), ("KeyZ", KeyCode::KeyZ),
+    ("Digit0", KeyCode::Digit0), ("Digit1", KeyCode::Digit1), ("Digit2", KeyCode::Digit2), ("Digit3", KeyCode::Digit3),
+    ("Digit4", KeyCode::Digit4), ("Digit5", KeyCode::Digit5), ("Digit6", KeyCode::Digit6), ("Digit7", KeyCode::Digit7),
+    ("Digit8", KeyCode::Digit8), ("Digit9", KeyCode::Digit9),
+    ("F1", KeyCode::F1), ("F2", KeyCode::F2), ("F3", KeyCode::F3), ("F4", KeyCode::F4),
+    ("F5", KeyCode::F5), ("F6", KeyCode::F6), ("F7", KeyCode::F7), ("F8", KeyCode::F8),
+    ("F9", KeyCode::F9), ("F10", KeyCode::F10), ("F11", KeyCode::F11), ("F12", KeyCode::F12),
+    ("ArrowUp", KeyCode::ArrowUp), ("ArrowDown", KeyCode::ArrowDown),
+    ("ArrowLeft", KeyCode::ArrowLeft), ("ArrowRight", KeyCode::ArrowRight),
+    ("Space", KeyCode::Space), ("Enter", KeyCode::Enter), ("Escape", KeyCode::Escape),
+    ("Tab", KeyCode::Tab), ("Backspace", KeyCode::Backspace),
+    ("ShiftLeft", KeyCode::ShiftLeft), ("ShiftRight", KeyCode::ShiftRight),
+    ("ControlLeft", KeyCode::ControlLeft), ("ControlRight", KeyCode::ControlRight),
+    ("AltLeft", KeyCode::AltLeft), ("AltRight", KeyCode::AltRight),
+    ("BracketLeft", KeyCode::BracketLeft), ("BracketRight", KeyCode::BracketRight),
+    ("Minus", KeyCode::Minus), ("Equal", KeyCode::Equal), ("Comma", KeyCode::Comma), ("Period", KeyCode::Period),
+    ("Slash", KeyCode::Slash), ("Backslash", KeyCode::Backslash), ("Semicolon", KeyCode::Semicolon),
+    ("Quote", KeyCode::Quote), ("Backquote", KeyCode::Backquote),
+];
+
+pub fn key_from_name(name: &str) -> Option<KeyCode> {
+    KEY_NAMES.iter().find(|(key_name, _)| *key_name == name).map(|(_, key)| *key)
+}
+
+pub fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::ArrowUp => "↑".to_string(),
+        KeyCode::ArrowDown => "↓".to_string(),
+        KeyCode::ArrowLeft => "←".to_string(),
+        KeyCode::ArrowRight => "→".to_string(),
+        KeyCode::Escape => "Esc".to_string(),
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => "Shift".to_string(),
+        KeyCode::ControlLeft | KeyCode::ControlRight => "Ctrl".to_string(),
+        KeyCode::AltLeft | KeyCode::AltRight => "Alt".to_string(),
+        KeyCode::BracketLeft => "[".to_string(),
+        KeyCode::BracketRight => "]".to_string(),
+        _ => {
+            let name = KEY_NAMES.iter().find(|(_, k)| *k == key).map(|(name, _)| *name).unwrap_or("?");
+            name.trim_start_matches("Key").trim_start_matches("Digit").to_string()
+        }
+    }
+}
+
+// Gamepad buttons are written with a Gamepad prefix, e.g. "GamepadSouth"; names follow Bevy's
+// GamepadButton, whose face buttons are named by position
+const BUTTON_NAMES: &[(&str, GamepadButton)] = &[
+    ("GamepadSouth", GamepadButton::South), ("GamepadEast", GamepadButton::East),
+    ("GamepadNorth", GamepadButton::North), ("GamepadWest", GamepadButton::West),
+    ("GamepadLeftTrigger", GamepadButton::LeftTrigger), ("GamepadLeftTrigger2", GamepadButton::LeftTrigger2),
+    ("GamepadRightTrigger", GamepadButton::RightTrigger), ("GamepadRightTrigger2", GamepadButton::RightTrigger2),
+    ("GamepadSelect", GamepadButton::Select), ("GamepadStart", GamepadButton::Start),
+    ("GamepadLeftThumb", GamepadButton::LeftThumb), ("GamepadRightThumb", GamepadButton::RightThumb),
+    ("GamepadDPadUp", GamepadButton::DPadUp), ("GamepadDPadDown", GamepadButton::DPadDown),
+    ("GamepadDPadLeft", GamepadButton::DPadLeft), ("GamepadDPadRight", GamepadButton::DPadRight),
+];
+
+pub fn button_from_name(name: &str) -> Option<GamepadButton> {
+    BUTTON_NAMES.iter().find(|(button_name, _)| *button_name == name).map(|(_, button)| *button)
+}
+
+// Labels follow the common Xbox layout
+pub fn button_label(button: GamepadButton) -> &'static str {
+    match button {
+        GamepadButton::South => "Ⓐ",
+        GamepadButton::East => "Ⓑ",
+        GamepadButton::West => "Ⓧ",
+        GamepadButton::North => "Ⓨ",
+        GamepadButton::LeftTrigger => "LB",
+        GamepadButton::LeftTrigger2 => "LT",
+        GamepadButton::RightTrigger => "RB",
+        GamepadButton::RightTrigger2 => "RT",
+        GamepadButton::Select => "View",
+        GamepadButton::Start => "Menu",
+        GamepadButton::LeftThumb => "LS",
+        GamepadButton::RightThumb => "RS",
+        GamepadButton::DPadUp => "D-Pad↑",
+        GamepadButton::DPadDown => "D-Pad↓",
+        GamepadButton::DPadLeft => "D-Pad←",
+        GamepadButton::DPadRight => "D-Pad→",
+        _ => "?",
+    }
+}
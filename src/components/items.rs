@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use super::{AnimalType, Item, ItemCategory, ItemType, LocalizedText, DEFAULT_LOCALE};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemDefinition {
+    pub item_type: ItemType,
+    pub name: LocalizedText,
+    pub description: LocalizedText,
+    pub category: ItemCategory,
+    pub value: u32,
+    pub color: [f32; 3],
+    pub icon: String,
+    pub hunger_restore: f32,
+    pub player_hunger_restore: f32,
+    pub friendship_bonus: u32,
+    pub compatible_animals: Vec<AnimalType>,
+}
+
+impl ItemDefinition {
+    fn placeholder(item_type: ItemType) -> Self {
+        Self {
+            item_type,
+            name: LocalizedText(HashMap::from([(DEFAULT_LOCALE.to_string(), format!("{:?}", item_type))])),
+            description: LocalizedText::default(),
+            category: ItemCategory::Special,
+            value: 0,
+            color: [0.5, 0.5, 0.5],
+            icon: "?".to_string(),
+            hunger_restore: 0.0,
+            player_hunger_restore: 0.0,
+            friendship_bonus: 0,
+            compatible_animals: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.get(DEFAULT_LOCALE)
+    }
+
+    pub fn color(&self) -> Color {
+        Color::srgb(self.color[0], self.color[1], self.color[2])
+    }
+}
+
+// Layout of assets/data/items.ron
+#[derive(Deserialize)]
+pub struct ItemDataFile {
+    pub items: Vec<ItemDefinition>,
+    pub starting_inventory: Vec<(ItemType, u32)>,
+}
+
+#[derive(Resource)]
+pub struct ItemRegistry {
+    definitions: HashMap<ItemType, ItemDefinition>,
+    pub starting_inventory: Vec<Item>,
+}
+
+impl ItemRegistry {
+    pub fn new(data: ItemDataFile) -> Self {
+        let mut definitions = HashMap::new();
+        for definition in data.items {
+            if definitions.insert(definition.item_type, definition).is_some() {
+                println!("物品数据重复定义，使用最后一项");
+            }
+        }
+
+        // Every item type must resolve, so fill gaps with a visible placeholder
+        for item_type in ItemType::ALL {
+            definitions.entry(item_type).or_insert_with(|| {
+                println!("物品 {:?} 缺少数据定义", item_type);
+                ItemDefinition::placeholder(item_type)
+            });
+        }
+
+        let starting_inventory = data
+            .starting_inventory
+            .into_iter()
+            .map(|(item_type, quantity)| Item::new(item_type, quantity))
+            .collect();
+
+        Self {
+            definitions,
+            starting_inventory,
+        }
+    }
+
+    pub fn get(&self, item_type: ItemType) -> &ItemDefinition {
+        &self.definitions[&item_type]
+    }
+
+    pub fn name(&self, item_type: ItemType) -> &str {
+        self.get(item_type).name()
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+use super::{Season, TimeOfDay, WeatherType};
+
+pub const DEFAULT_LOCALE: &str = "zh-CN";
+
+const WEEKDAY_KEYS: [&str; 7] = [
+    "weekday.monday", "weekday.tuesday", "weekday.wednesday", "weekday.thursday",
+    "weekday.friday", "weekday.saturday", "weekday.sunday",
+];
+
+// Text keyed by locale code, e.g. { "zh-CN": "苹果", "en": "Apple" }
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct LocalizedText(pub HashMap<String, String>);
+
+impl LocalizedText {
+    pub fn get(&self, locale: &str) -> &str {
+        self.0
+            .get(locale)
+            .or_else(|| self.0.get(DEFAULT_LOCALE))
+            .or_else(|| self.0.values().next())
+            .map(String::as_str)
+            .unwrap_or("???")
+    }
+}
+
+// Layout of assets/locales/<locale>.ron
+#[derive(Deserialize)]
+pub struct LocaleFile {
+    // Shown in the language setting, in the language itself
+    pub name: String,
+    pub strings: HashMap<String, String>,
+}
+
+// UI string tables for every bundled locale and the one currently in use.
+// Console logs stay in the default locale.
+#[derive(Resource)]
+pub struct Localization {
+    locale: String,
+    // In the order the language setting cycles through them
+    locales: Vec<(String, LocaleFile)>,
+}
+
+impl Localization {
+    pub fn new(locales: Vec<(String, LocaleFile)>, locale: &str) -> Self {
+        let mut localization = Self {
+            locale: DEFAULT_LOCALE.to_string(),
+            locales,
+        };
+        if !localization.set_locale(locale) {
+            println!("不支持的语言 {}，使用 {}", locale, DEFAULT_LOCALE);
+        }
+        localization
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    pub fn set_locale(&mut self, locale: &str) -> bool {
+        if !self.locales.iter().any(|(code, _)| code == locale) {
+            return false;
+        }
+        self.locale = locale.to_string();
+        true
+    }
+
+    pub fn next_locale(&mut self) {
+        let index = self.locales.iter().position(|(code, _)| *code == self.locale).unwrap_or(0);
+        if let Some((code, _)) = self.locales.get((index + 1) % self.locales.len().max(1)) {
+            self.locale = code.clone();
+        }
+    }
+
+    pub fn language_name(&self) -> &str {
+        self.table(&self.locale).map(|file| file.name.as_str()).unwrap_or(&self.locale)
+    }
+
+    fn table(&self, locale: &str) -> Option<&LocaleFile> {
+        self.locales.iter().find(|(code, _)| code == locale).map(|(_, file)| file)
+    }
+
+    // Missing strings fall back to the default locale, then to the key itself so they stand out
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        [self.locale.as_str(), DEFAULT_LOCALE]
+            .into_iter()
+            .find_map(|locale| self.table(locale)?.strings.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+
+    // Fills `{name}` placeholders, e.g. format("summary.title", &[("day", &3)])
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = self.get(key).to_string();
+        for (name, value) in args {
+            text = text.replace(&format!("{{{}}}", name), &value.to_string());
+        }
+        text
+    }
+
+    // Item, animal and quest text that comes with its own translations
+    pub fn text<'a>(&self, text: &'a LocalizedText) -> &'a str {
+        text.get(&self.locale)
+    }
+
+    pub fn season_name(&self, season: Season) -> &str {
+        self.get(match season {
+            Season::Spring => "season.spring",
+            Season::Summer => "season.summer",
+            Season::Autumn => "season.autumn",
+            Season::Winter => "season.winter",
+        })
+    }
+
+    pub fn weather_name(&self, weather: WeatherType) -> &str {
+        self.get(match weather {
+            WeatherType::Sunny => "weather.sunny",
+            WeatherType::Cloudy => "weather.cloudy",
+            WeatherType::Rainy => "weather.rainy",
+            WeatherType::Snowy => "weather.snowy",
+        })
+    }
+
+    pub fn date(&self, time_of_day: &TimeOfDay) -> String {
+        self.format("hud.date", &[
+            ("year", &time_of_day.year()),
+            ("season", &self.season_name(time_of_day.season())),
+            ("day", &time_of_day.day_of_season()),
+            ("weekday", &self.get(WEEKDAY_KEYS[time_of_day.weekday() as usize])),
+        ])
+    }
+}
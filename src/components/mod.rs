@@ -1,4 +1,50 @@
 use bevy::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+mod collision;
+mod input;
+mod items;
+mod localization;
+mod quests;
+mod species;
+mod weather;
+pub use collision::*;
+pub use input::*;
+pub use items::*;
+pub use localization::*;
+pub use quests::*;
+pub use species::*;
+pub use weather::*;
+
+// Top-level flow of the game; the simulation only runs while Playing
+#[derive(States, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GameState {
+    #[default]
+    Title,
+    // The farm is being spawned, either fresh or from the save
+    Loading,
+    Playing,
+    Paused,
+    // Between falling asleep and closing the day summary
+    DayTransition,
+}
+
+// Active whenever a farm exists, so the HUD keeps showing behind the pause menu
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InGame;
+
+impl ComputedStates for InGame {
+    type SourceStates = GameState;
+
+    fn compute(state: GameState) -> Option<Self> {
+        match state {
+            GameState::Playing | GameState::Paused | GameState::DayTransition => Some(InGame),
+            GameState::Title | GameState::Loading => None,
+        }
+    }
+}
 
 // Player components
 #[derive(Component)]
@@ -12,16 +58,93 @@ pub struct PlayerDirection {
     pub direction: Vec2,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Facing {
+    Up,
+    #[default]
+    Down,
+    Left,
+    Right,
+}
+
+impl Facing {
+    // The dominant axis of the movement wins, so diagonals don't flicker between poses
+    pub fn from_movement(movement: Vec2) -> Option<Self> {
+        if movement == Vec2::ZERO {
+            None
+        } else if movement.x.abs() > movement.y.abs() {
+            Some(if movement.x < 0.0 { Facing::Left } else { Facing::Right })
+        } else {
+            Some(if movement.y < 0.0 { Facing::Down } else { Facing::Up })
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlayerClip {
+    #[default]
+    Idle,
+    Walk,
+    Run,
+    UseTool,
+}
+
 #[derive(Component)]
 pub struct PlayerAnimation {
-    pub frame_timer: Timer,
-    pub current_frame: usize,
-    pub is_walking: bool,
-    pub facing_left: bool,
+    pub clip: PlayerClip,
+    pub facing: Facing,
+    // Seconds into the current clip
+    pub clip_time: f32,
+    // Counts down the tool swing; it plays over walking and idling until finished
+    pub tool_timer: Timer,
 }
 
-#[derive(Component)]
-pub struct PlayerPart;
+impl PlayerAnimation {
+    pub fn new() -> Self {
+        let mut tool_timer = Timer::from_seconds(0.4, TimerMode::Once);
+        let duration = tool_timer.duration();
+        tool_timer.tick(duration);
+        Self {
+            clip: PlayerClip::Idle,
+            facing: Facing::Down,
+            clip_time: 0.0,
+            tool_timer,
+        }
+    }
+
+    pub fn set_clip(&mut self, clip: PlayerClip) {
+        if self.clip != clip {
+            self.clip = clip;
+            self.clip_time = 0.0;
+        }
+    }
+
+    pub fn use_tool(&mut self) {
+        self.tool_timer.reset();
+        self.set_clip(PlayerClip::UseTool);
+    }
+}
+
+// Child sprites of the player entity, posed by PlayerSystems::animate_player
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlayerPart {
+    Shadow,
+    LeftLeg,
+    RightLeg,
+    LeftFoot,
+    RightFoot,
+    Body,
+    LeftArm,
+    RightArm,
+    LeftHand,
+    RightHand,
+    Head,
+    Hair,
+    LeftEye,
+    RightEye,
+    Mouth,
+    Tool,
+}
 
 #[derive(Resource)]
 pub struct PlayerStats {
@@ -29,7 +152,6 @@ pub struct PlayerStats {
     pub fatigue: f32,
     pub energy: f32,
     pub coins: u32,
-    pub day_survived: u32,
 }
 
 impl PlayerStats {
@@ -39,7 +161,6 @@ impl PlayerStats {
             fatigue: 0.0,
             energy: 100.0,
             coins: 50,
-            day_survived: 1,
         }
     }
     
@@ -92,38 +213,26 @@ pub struct AnimalAI {
 
 #[derive(Component)]
 pub struct AnimalProduction {
-    pub production_type: ProductionType,
+    pub product: Option<ItemType>,
     pub production_timer: Timer,
     pub can_produce: bool,
     pub last_production_time: u32,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum ProductionType {
-    Eggs,
-    Milk,
-    Wool,
-    None,
-}
-
 impl AnimalProduction {
-    pub fn new(animal_type: AnimalType) -> Self {
-        let production_type = match animal_type {
-            AnimalType::Chicken => ProductionType::Eggs,
-            AnimalType::Cow => ProductionType::Milk,
-            AnimalType::Sheep => ProductionType::Wool,
-            _ => ProductionType::None,
-        };
-        
-        let production_time = match production_type {
-            ProductionType::Eggs => std::time::Duration::from_secs(300), // 5 minutes
-            ProductionType::Milk => std::time::Duration::from_secs(600),  // 10 minutes
-            ProductionType::Wool => std::time::Duration::from_secs(1800), // 30 minutes
-            ProductionType::None => std::time::Duration::from_secs(u64::MAX),
+    pub fn new(species: &SpeciesDefinition) -> Self {
+        // The timer counts game minutes as seconds, so it follows the clock speed and also
+        // runs through the time skipped while sleeping
+        let (product, production_time) = match &species.production {
+            Some(production) => (
+                Some(production.item),
+                std::time::Duration::from_secs_f32(production.interval_minutes),
+            ),
+            None => (None, std::time::Duration::from_secs(u64::MAX)),
         };
         
         Self {
-            production_type,
+            product,
             production_timer: Timer::new(production_time, TimerMode::Repeating),
             can_produce: false,
             last_production_time: 0,
@@ -143,12 +252,7 @@ impl AnimalProduction {
         if self.can_produce {
             self.can_produce = false;
             self.production_timer.reset();
-            match self.production_type {
-                ProductionType::Eggs => Some(ItemType::Egg),
-                ProductionType::Milk => Some(ItemType::Milk),
-                ProductionType::Wool => Some(ItemType::Wool),
-                ProductionType::None => None,
-            }
+            self.product
         } else {
             None
         }
@@ -157,12 +261,11 @@ impl AnimalProduction {
 
 #[derive(Component)]
 pub struct Interactable {
-    pub interaction_type: InteractionType,
     pub interaction_range: f32,
 }
 
 // Animal types and states
-#[derive(PartialEq, Clone, Copy, Debug, Eq, Hash)]
+#[derive(PartialEq, Clone, Copy, Debug, Eq, Hash, Serialize, Deserialize)]
 pub enum AnimalType {
     Dog,
     Cat,
@@ -174,7 +277,20 @@ pub enum AnimalType {
     Horse,
 }
 
-#[derive(PartialEq, Debug, Eq, Hash, Clone, Copy)]
+impl AnimalType {
+    pub const ALL: [AnimalType; 8] = [
+        AnimalType::Dog,
+        AnimalType::Cat,
+        AnimalType::Chicken,
+        AnimalType::Cow,
+        AnimalType::Sheep,
+        AnimalType::Pig,
+        AnimalType::Duck,
+        AnimalType::Horse,
+    ];
+}
+
+#[derive(PartialEq, Debug, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum AnimalState {
     Idle,
     Wandering,
@@ -189,6 +305,16 @@ pub enum InteractionType {
     Play,
 }
 
+impl InteractionType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            InteractionType::Pet => "抚摸",
+            InteractionType::Feed => "喂食",
+            InteractionType::Play => "玩耍",
+        }
+    }
+}
+
 // Time system components
 #[derive(Resource)]
 pub struct TimeOfDay {
@@ -196,16 +322,99 @@ pub struct TimeOfDay {
     pub minute: u32,
     pub time_scale: f32,
     pub current_phase: DayPhase,
+    // Days since the game started, counting from 1; season, year and weekday derive from it
+    pub day: u32,
+    // Fraction of a game minute carried over between frames
+    pub minute_progress: f32,
+    pub paused: bool,
+}
+
+// Default clock speed: 1 real second = 60 game minutes
+pub const BASE_TIME_SCALE: f32 = 60.0;
+
+// Below this temperature (°C) the player tires faster and some animals produce less
+pub const COLD_TEMPERATURE: f32 = 5.0;
+
+// Full-view sprite parented to the camera that tints everything below it
+#[derive(Component, Default)]
+pub struct DayNightOverlay {
+    pub weather_dimming: f32,
+}
+
+pub const DAYS_PER_SEASON: u32 = 28;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub const ALL: [Season; 4] = [Season::Spring, Season::Summer, Season::Autumn, Season::Winter];
+
+    pub fn for_day(day: u32) -> Season {
+        Season::ALL[((day - 1) / DAYS_PER_SEASON % 4) as usize]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Season::Spring => "春",
+            Season::Summer => "夏",
+            Season::Autumn => "秋",
+            Season::Winter => "冬",
+        }
+    }
+}
+
+impl TimeOfDay {
+    // 1-based day within the current season
+    pub fn day_of_season(&self) -> u32 {
+        (self.day - 1) % DAYS_PER_SEASON + 1
+    }
+
+    pub fn season(&self) -> Season {
+        Season::for_day(self.day)
+    }
+
+    pub fn year(&self) -> u32 {
+        Self::year_for_day(self.day)
+    }
+
+    pub fn year_for_day(day: u32) -> u32 {
+        (day - 1) / (DAYS_PER_SEASON * 4) + 1
+    }
+
+    // 0 = Monday; day 1 is a Monday
+    pub fn weekday(&self) -> u32 {
+        (self.day - 1) % 7
+    }
+
+    pub fn weekday_name(&self) -> &'static str {
+        ["周一", "周二", "周三", "周四", "周五", "周六", "周日"][self.weekday() as usize]
+    }
+
+    pub fn get_date_string(&self) -> String {
+        format!("第{}年 {}季 {}日 {}", self.year(), self.season().name(), self.day_of_season(), self.weekday_name())
+    }
+
+    // Moves the calendar to the next day without touching the clock
+    pub fn advance_day(&mut self) {
+        self.day += 1;
+    }
 }
 
 #[derive(Resource)]
 pub struct Weather {
     pub current_weather: WeatherType,
     pub temperature: f32,
-    pub change_timer: Timer,
+    // Game hours since day 1 at which the weather last had a chance to change
+    pub last_change_hour: Option<u32>,
+    pub forecast: Option<WeatherForecast>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WeatherType {
     Sunny,
     Cloudy,
@@ -213,52 +422,48 @@ pub enum WeatherType {
     Snowy,
 }
 
+impl WeatherType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeatherType::Sunny => "晴天",
+            WeatherType::Cloudy => "多云",
+            WeatherType::Rainy => "雨天",
+            WeatherType::Snowy => "雪天",
+        }
+    }
+
+    // Rain and snow send the animals under the barn
+    pub fn sends_animals_to_shelter(&self) -> bool {
+        matches!(self, WeatherType::Rainy | WeatherType::Snowy)
+    }
+}
+
 impl Weather {
     pub fn new() -> Self {
         Self {
             current_weather: WeatherType::Sunny,
             temperature: 20.0,
-            change_timer: Timer::from_seconds(300.0, TimerMode::Repeating), // Change weather every 5 minutes
-        }
-    }
-    
-    pub fn update(&mut self, delta: std::time::Duration) {
-        self.change_timer.tick(delta);
-        if self.change_timer.just_finished() {
-            self.change_weather();
-        }
-    }
-    
-    fn change_weather(&mut self) {
-        use WeatherType::*;
-        let weather_types = [Sunny, Cloudy, Rainy];
-        self.current_weather = weather_types[fastrand::usize(..) % weather_types.len()];
-        
-        // Adjust temperature based on weather
-        match self.current_weather {
-            Sunny => self.temperature = 20.0 + fastrand::f32() * 10.0,
-            Cloudy => self.temperature = 15.0 + fastrand::f32() * 10.0,
-            Rainy => self.temperature = 10.0 + fastrand::f32() * 10.0,
-            Snowy => self.temperature = -5.0 + fastrand::f32() * 10.0,
-        }
-    }
-    
-    pub fn get_weather_name(&self) -> &'static str {
-        match self.current_weather {
-            WeatherType::Sunny => "晴天",
-            WeatherType::Cloudy => "多云",
-            WeatherType::Rainy => "雨天",
-            WeatherType::Snowy => "雪天",
+            last_change_hour: None,
+            forecast: None,
         }
     }
 }
 
 // Quest system components
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct QuestManager {
     pub active_quests: Vec<Quest>,
     pub completed_quests: Vec<Quest>,
     pub daily_quests: Vec<Quest>,
+    #[serde(default)]
+    pub failed_quests: Vec<Quest>,
+    // Day the quest board was last rolled over for; 0 until the first rollover
+    #[serde(default)]
+    pub current_day: u32,
+    #[serde(default)]
+    pub completion_log: Vec<QuestCompletion>,
+    #[serde(default)]
+    pub chains: Vec<QuestChainProgress>,
 }
 
 impl QuestManager {
@@ -267,6 +472,10 @@ impl QuestManager {
             active_quests: Vec::new(),
             completed_quests: Vec::new(),
             daily_quests: Vec::new(),
+            failed_quests: Vec::new(),
+            current_day: 0,
+            completion_log: Vec::new(),
+            chains: Vec::new(),
         }
     }
     
@@ -281,14 +490,43 @@ impl QuestManager {
         }
     }
     
-    pub fn update_quest_progress(&mut self, quest_id: &str, progress: u32) {
-        if let Some(quest) = self.active_quests.iter_mut().find(|q| q.id == quest_id) {
-            quest.progress = progress.min(quest.required_amount);
+    pub fn chain_status(&self, chain_id: &str) -> Option<ChainStatus> {
+        self.chains.iter().find(|chain| chain.chain_id == chain_id).map(|chain| chain.status)
+    }
+    
+    // Drops every objective of a chain stage, e.g. the branches that weren't taken
+    pub fn remove_stage_quests(&mut self, chain_id: &str, stage_id: &str) {
+        self.active_quests.retain(|quest| {
+            !quest.chain.as_ref().is_some_and(|link| link.chain_id == chain_id && link.stage_id == stage_id)
+        });
+    }
+    
+    // Yesterday's unfinished dailies and anything past its deadline move to the failed list
+    pub fn expire_quests(&mut self, day: u32) -> Vec<Quest> {
+        let (expired, active): (Vec<Quest>, Vec<Quest>) = self.active_quests
+            .drain(..)
+            .partition(|quest| quest.is_daily || quest.deadline.is_some_and(|deadline| deadline <= day));
+        
+        self.active_quests = active;
+        self.failed_quests.extend(expired.iter().cloned());
+        expired
+    }
+    
+    // Advance every active quest of this type whose filters accept the event
+    pub fn record_progress(&mut self, quest_type: QuestType, animal: Option<(Entity, AnimalType)>, item_type: Option<ItemType>, amount: u32) {
+        let animal_type = animal.map(|(_, animal_type)| animal_type);
+        for quest in self.active_quests.iter_mut() {
+            if quest.quest_type == quest_type && quest.matches(animal_type, item_type) {
+                quest.progress = (quest.progress + amount).min(quest.required_amount);
+                if let Some((entity, _)) = animal.filter(|(entity, _)| !quest.involved_animals.contains(entity)) {
+                    quest.involved_animals.push(entity);
+                }
+            }
         }
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Quest {
     pub id: String,
     pub title: String,
@@ -299,9 +537,30 @@ pub struct Quest {
     pub reward: QuestReward,
     pub is_daily: bool,
     pub deadline: Option<u32>, // Day number
+    // Optional filters, e.g. "feed 3 cows" or "collect 2 eggs"
+    #[serde(default)]
+    pub target_animal: Option<AnimalType>,
+    #[serde(default)]
+    pub target_item: Option<ItemType>,
+    #[serde(default)]
+    pub penalty: Option<QuestPenalty>,
+    // Set when this quest is one objective of a quest chain stage
+    #[serde(default)]
+    pub chain: Option<QuestChainLink>,
+    // Animals that contributed progress; entities don't survive a reload, so not saved
+    #[serde(skip)]
+    pub involved_animals: Vec<Entity>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+impl Quest {
+    pub fn matches(&self, animal_type: Option<AnimalType>, item_type: Option<ItemType>) -> bool {
+        let animal_ok = self.target_animal.is_none() || self.target_animal == animal_type;
+        let item_ok = self.target_item.is_none() || self.target_item == item_type;
+        animal_ok && item_ok
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum QuestType {
     FeedAnimals,
     CollectItems,
@@ -309,16 +568,89 @@ pub enum QuestType {
     InteractWithAnimals,
     PlantFlowers,
     WaterPlants,
+    UseItems,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QuestReward {
     pub coins: u32,
     pub items: Vec<(ItemType, u32)>,
     pub friendship_bonus: u32,
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuestChainLink {
+    pub chain_id: String,
+    pub stage_id: String,
+    // Stage reached by completing this objective; None finishes the chain
+    pub next_stage: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ChainStatus {
+    Active,
+    Completed,
+    Failed,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuestChainProgress {
+    pub chain_id: String,
+    pub title: String,
+    pub current_stage: Option<String>,
+    pub completed_stages: Vec<String>,
+    pub status: ChainStatus,
+}
+
+// What a completed quest actually granted
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuestCompletion {
+    pub quest_id: String,
+    pub title: String,
+    pub day: u32,
+    pub coins: u32,
+    pub items_received: Vec<(ItemType, u32)>,
+    pub items_mailed: Vec<(ItemType, u32)>,
+    pub friendship_bonus: u32,
+    pub animals_rewarded: usize,
+}
+
+// Reward items that didn't fit in the inventory, waiting to be claimed
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct RewardMailbox {
+    pub items: Vec<Item>,
+}
+
+impl RewardMailbox {
+    pub fn deposit(&mut self, item: Item) {
+        match self.items.iter_mut().find(|existing| existing.item_type == item.item_type) {
+            Some(existing) => existing.quantity += item.quantity,
+            None => self.items.push(item),
+        }
+    }
+
+    // Moves as much as fits into the inventory and returns what was claimed
+    pub fn claim_into(&mut self, inventory: &mut Inventory) -> Vec<Item> {
+        let mut claimed = Vec::new();
+        self.items.retain(|item| {
+            if inventory.add_item(item.clone()) {
+                claimed.push(item.clone());
+                false
+            } else {
+                true
+            }
+        });
+        claimed
+    }
+}
+
+// Applied when a quest fails
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuestPenalty {
+    pub coins: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum DayPhase {
     Morning,
     Noon,
@@ -326,7 +658,130 @@ pub enum DayPhase {
     Night,
 }
 
+impl DayPhase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DayPhase::Morning => "早晨",
+            DayPhase::Noon => "中午",
+            DayPhase::Evening => "傍晚",
+            DayPhase::Night => "夜晚",
+        }
+    }
+
+    // Animals rest through the midday heat and sleep at night
+    pub fn is_rest_time(&self) -> bool {
+        matches!(self, DayPhase::Noon | DayPhase::Night)
+    }
+}
+
+// Gameplay events, emitted where the action happens and consumed by quests and stats
+#[derive(Event, Clone, Copy, Debug)]
+pub struct AnimalFed {
+    pub entity: Entity,
+    pub animal_type: AnimalType,
+    pub item_type: ItemType,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct AnimalPetted {
+    pub entity: Entity,
+    pub animal_type: AnimalType,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct AnimalPlayedWith {
+    pub entity: Entity,
+    pub animal_type: AnimalType,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ProductCollected {
+    pub entity: Entity,
+    pub animal_type: AnimalType,
+    pub item_type: ItemType,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ItemAcquired {
+    pub item_type: ItemType,
+    pub quantity: u32,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ItemUsed {
+    pub item_type: ItemType,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct FriendshipChanged {
+    pub animal_type: AnimalType,
+    pub amount: u32,
+}
+
+#[derive(Event, Clone, Debug)]
+pub struct QuestCompleted {
+    pub quest: Quest,
+    pub coins: u32,
+}
+
+#[derive(Event, Clone, Debug)]
+pub struct QuestFailed {
+    pub quest: Quest,
+    // Coins actually taken, which can be less than the penalty when the player is short
+    pub coins_lost: u32,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlayerSlept;
+
+// Calendar events, sent once the date has moved (midnight or sleeping)
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DayChanged {
+    pub day: u32,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SeasonChanged {
+    pub season: Season,
+    pub year: u32,
+}
+
+// Clock events, so systems don't have to poll hour and minute every frame. One is sent
+// for every hour and phase passed, including those skipped by sleeping or high time speeds.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct HourChanged {
+    pub day: u32,
+    pub hour: u32,
+}
+
+impl HourChanged {
+    // Game hours since day 1 started
+    pub fn absolute_hour(&self) -> u32 {
+        (self.day - 1) * 24 + self.hour
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PhaseChanged {
+    pub phase: DayPhase,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct WeatherChanged {
+    pub weather: WeatherType,
+}
+
+// Game time jumped forward without being ticked, e.g. while the player slept
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TimeSkipped {
+    pub minutes: f32,
+}
+
 // Inventory components
+
+// Slots shown on the HUD, each with its own SelectSlot binding
+pub const HOTBAR_SLOTS: u8 = 8;
+
 #[derive(Resource)]
 pub struct Inventory {
     pub items: Vec<Item>,
@@ -335,17 +790,14 @@ pub struct Inventory {
     pub is_open: bool,
 }
 
-#[derive(Clone, Debug)]
+// Names, values and other metadata live in the ItemRegistry
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Item {
     pub item_type: ItemType,
     pub quantity: u32,
-    pub name: String,
-    pub category: ItemCategory,
-    pub value: u32,
-    pub description: String,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ItemCategory {
     Food,
     Tool,
@@ -353,7 +805,7 @@ pub enum ItemCategory {
     Special,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ItemType {
     // 食物类
     Apple,
@@ -428,93 +880,56 @@ impl Inventory {
 }
 
 impl Item {
-    pub fn get_item_color(&self) -> Color {
-        match self.item_type {
-            // 食物类 - 更鲜艳、更区分的颜色
-            ItemType::Apple => Color::srgb(0.9, 0.2, 0.2),      // 鲜红色
-            ItemType::Carrot => Color::srgb(1.0, 0.6, 0.0),    // 亮橙色
-            ItemType::Bone => Color::srgb(0.95, 0.95, 0.95),   // 纯白色
-            ItemType::Fish => Color::srgb(0.0, 0.6, 1.0),      // 亮蓝色
-            ItemType::Milk => Color::srgb(1.0, 1.0, 0.9),      // 纯白色
-            ItemType::Wheat => Color::srgb(1.0, 0.9, 0.3),      // 金黄色
-            ItemType::Flower => Color::srgb(1.0, 0.4, 1.0),     // 亮粉色
-            ItemType::Corn => Color::srgb(1.0, 0.8, 0.0),       // 金黄色
-            ItemType::Hay => Color::srgb(0.8, 0.6, 0.2),        // 金棕色
-            
-            // 工具类 - 金属色和工具色
-            ItemType::WaterBucket => Color::srgb(0.3, 0.6, 0.8), // 蓝灰色
-            ItemType::Basket => Color::srgb(0.6, 0.3, 0.1),     // 深棕色
-            ItemType::Shovel => Color::srgb(0.6, 0.6, 0.6),     // 银色
-            ItemType::Rake => Color::srgb(0.5, 0.3, 0.1),       // 木色
-            
-            // 装饰类 - 鲜艳的装饰色
-            ItemType::Toy => Color::srgb(1.0, 0.2, 0.6),        // 粉红色
-            ItemType::Ribbon => Color::srgb(0.8, 0.2, 0.8),     // 紫色
-            ItemType::Bell => Color::srgb(1.0, 0.8, 0.2),       // 金色
-            
-            // 特殊物品 - 特殊颜色
-            ItemType::Egg => Color::srgb(1.0, 1.0, 0.9),        // 蛋白色
-            ItemType::Wool => Color::srgb(0.9, 0.9, 1.0),       // 浅蓝色
-            ItemType::ApplePie => Color::srgb(0.8, 0.3, 0.1),   // 深红色
-            ItemType::MilkBottle => Color::srgb(0.8, 0.9, 1.0), // 浅蓝色
-        }
+    pub fn new(item_type: ItemType, quantity: u32) -> Self {
+        Self { item_type, quantity }
     }
-    
-    pub fn get_item_symbol(&self) -> &'static str {
-        match self.item_type {
-            // 食物类 - 使用英文字母
-            ItemType::Apple => "A",      // Apple
-            ItemType::Carrot => "C",     // Carrot
-            ItemType::Bone => "B",       // Bone
-            ItemType::Fish => "F",       // Fish
-            ItemType::Milk => "M",       // Milk
-            ItemType::Wheat => "W",      // Wheat
-            ItemType::Flower => "L",     // Flower (L for Lily)
-            ItemType::Corn => "O",       // Corn (O for O-shaped)
-            ItemType::Hay => "H",        // Hay
-            
-            // 工具类 - 工具相关字母
-            ItemType::WaterBucket => "P", // Water Bucket (P for Pail)
-            ItemType::Basket => "K",     // Basket (K for Basket)
-            ItemType::Shovel => "S",     // Shovel
-            ItemType::Rake => "R",      // Rake
-            
-            // 装饰类 - 装饰相关字母
-            ItemType::Toy => "T",       // Toy
-            ItemType::Ribbon => "R",    // Ribbon
-            ItemType::Bell => "L",       // Bell
-            
-            // 特殊物品 - 特殊字母
-            ItemType::Egg => "E",       // Egg
-            ItemType::Wool => "W",      // Wool
-            ItemType::ApplePie => "P",   // Apple Pie
-            ItemType::MilkBottle => "B", // Milk Bottle
-        }
+}
+
+impl ItemType {
+    pub const ALL: [ItemType; 20] = [
+        ItemType::Apple,
+        ItemType::Carrot,
+        ItemType::Bone,
+        ItemType::Fish,
+        ItemType::Milk,
+        ItemType::Wheat,
+        ItemType::Flower,
+        ItemType::Corn,
+        ItemType::Hay,
+        ItemType::WaterBucket,
+        ItemType::Basket,
+        ItemType::Shovel,
+        ItemType::Rake,
+        ItemType::Toy,
+        ItemType::Ribbon,
+        ItemType::Bell,
+        ItemType::Egg,
+        ItemType::Wool,
+        ItemType::ApplePie,
+        ItemType::MilkBottle,
+    ];
+}
+
+// World generation
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct WorldSeed(pub u64);
+
+impl WorldSeed {
+    pub fn random() -> Self {
+        Self(rand::random())
     }
-    
-    pub fn get_item_name(&self) -> &'static str {
-        match self.item_type {
-            ItemType::Apple => "Apple",
-            ItemType::Carrot => "Carrot",
-            ItemType::Bone => "Bone",
-            ItemType::Fish => "Fish",
-            ItemType::Milk => "Milk",
-            ItemType::Wheat => "Wheat",
-            ItemType::Flower => "Flower",
-            ItemType::Corn => "Corn",
-            ItemType::Hay => "Hay",
-            ItemType::WaterBucket => "Bucket",
-            ItemType::Basket => "Basket",
-            ItemType::Shovel => "Shovel",
-            ItemType::Rake => "Rake",
-            ItemType::Toy => "Toy",
-            ItemType::Ribbon => "Ribbon",
-            ItemType::Bell => "Bell",
-            ItemType::Egg => "Egg",
-            ItemType::Wool => "Wool",
-            ItemType::ApplePie => "Pie",
-            ItemType::MilkBottle => "Bottle",
+
+    // Every generation step draws from its own stream, so tweaking one step
+    // (e.g. the number of houses) doesn't reshuffle everything generated after it.
+    // ChaCha8 is specified and portable, unlike StdRng, so a saved seed regenerates the
+    // same farm on every platform and after dependency updates.
+    pub fn rng(&self, stream: &str) -> ChaCha8Rng {
+        let mut hash = self.0 ^ 0xcbf2_9ce4_8422_2325;
+        for byte in stream.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
         }
+        ChaCha8Rng::seed_from_u64(hash)
     }
 }
 
@@ -532,4 +947,82 @@ pub struct FloatingText {
     pub velocity: Vec3,
     pub timer: Timer,
     pub color: Color,
-}
\ No newline at end of file
+}
+
+// Weather effect components
+// Smoothed weather values the visuals follow; intensity fades precipitation in and out,
+// snow cover and wetness build up over game hours and melt or dry afterwards
+#[derive(Resource, Default)]
+pub struct WeatherEffects {
+    pub intensity: f32,
+    pub falling_snow: bool,
+    pub cloud_cover: f32,
+    pub snow_cover: f32,
+    pub wetness: f32,
+}
+
+#[derive(Component)]
+pub struct WeatherParticle {
+    pub index: usize,
+    // Position relative to the camera, so the field always fills the view
+    pub offset: Vec2,
+    pub speed: f32,
+}
+
+#[derive(Component)]
+pub struct CloudShadow {
+    pub drift: Vec2,
+}
+
+// Ground sprites that turn white under snow
+#[derive(Component)]
+pub struct SnowCover {
+    pub base_color: Color,
+}
+
+// Everything that happened since the player last woke up, reported when they go to sleep
+#[derive(Resource, Default)]
+pub struct DailyStats {
+    pub coins_earned: u32,
+    pub coins_spent: u32,
+    pub products: Vec<(ItemType, u32)>,
+    pub animals_fed: u32,
+    pub animals_petted: u32,
+    pub friendship: Vec<(AnimalType, u32)>,
+    pub quests_completed: Vec<Quest>,
+    pub quests_failed: Vec<Quest>,
+}
+
+impl DailyStats {
+    pub fn add_product(&mut self, item_type: ItemType) {
+        match self.products.iter_mut().find(|(product, _)| *product == item_type) {
+            Some((_, count)) => *count += 1,
+            None => self.products.push((item_type, 1)),
+        }
+    }
+    
+    pub fn add_friendship(&mut self, animal_type: AnimalType, amount: u32) {
+        match self.friendship.iter_mut().find(|(animal, _)| *animal == animal_type) {
+            Some((_, total)) => *total += amount,
+            None => self.friendship.push((animal_type, amount)),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Puddle {
+    pub size: Vec2,
+}
+
+// Anything that grows and needs water; rain refills it, dry weather drains it
+#[derive(Component)]
+pub struct Plant {
+    pub hydration: f32,
+    pub base_color: Color,
+    // How wilted the sprite was last drawn, to skip recoloring when nothing changed
+    pub wilt_applied: f32,
+}
+
+// Buildings animals run to when it rains or snows
+#[derive(Component)]
+pub struct Shelter;
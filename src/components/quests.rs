@@ -0,0 +1,251 @@
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use serde::Deserialize;
+use std::collections::HashMap;
+use super::{AnimalType, ChainStatus, ItemType, LocalizedText, Quest, QuestChainLink, QuestManager, QuestPenalty, QuestReward, QuestType, DEFAULT_LOCALE};
+
+fn default_weight() -> u32 {
+    1
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuestDefinition {
+    pub id: String,
+    pub title: LocalizedText,
+    pub description: LocalizedText,
+    pub quest_type: QuestType,
+    pub required_amount: u32,
+    #[serde(default)]
+    pub target_animal: Option<AnimalType>,
+    #[serde(default)]
+    pub target_item: Option<ItemType>,
+    pub reward: QuestReward,
+    pub is_daily: bool,
+    // Relative chance of being drawn into the daily pool
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    // Days the player has to finish the quest once it is issued
+    #[serde(default)]
+    pub deadline_days: Option<u32>,
+    // Charged if the quest expires unfinished
+    #[serde(default)]
+    pub penalty: Option<QuestPenalty>,
+    // Quest ids that must have been completed before this one is offered
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+}
+
+impl QuestDefinition {
+    pub fn instantiate(&self, day: u32) -> Quest {
+        Quest {
+            id: self.id.clone(),
+            title: self.title.get(DEFAULT_LOCALE).to_string(),
+            description: self.description.get(DEFAULT_LOCALE).to_string(),
+            quest_type: self.quest_type,
+            required_amount: self.required_amount,
+            progress: 0,
+            reward: self.reward.clone(),
+            is_daily: self.is_daily,
+            deadline: self.deadline_days.map(|days| day + days),
+            target_animal: self.target_animal,
+            target_item: self.target_item,
+            penalty: self.penalty.clone(),
+            chain: None,
+            involved_animals: Vec::new(),
+        }
+    }
+
+    pub fn prerequisites_met(&self, completed: &[Quest]) -> bool {
+        self.prerequisites
+            .iter()
+            .all(|id| completed.iter().any(|quest| &quest.id == id))
+    }
+}
+
+// Layout of assets/data/quests.ron
+#[derive(Deserialize)]
+pub struct QuestDataFile {
+    pub daily_quest_count: usize,
+    pub quests: Vec<QuestDefinition>,
+}
+
+#[derive(Resource)]
+pub struct QuestCatalog {
+    pub daily_quest_count: usize,
+    definitions: Vec<QuestDefinition>,
+}
+
+impl QuestCatalog {
+    pub fn new(data: QuestDataFile) -> Self {
+        Self {
+            daily_quest_count: data.daily_quest_count,
+            definitions: data.quests,
+        }
+    }
+
+    // Weighted draw without replacement from the dailies whose prerequisites are met
+    pub fn draw_daily(&self, rng: &mut impl Rng, completed: &[Quest]) -> Vec<&QuestDefinition> {
+        let pool: Vec<&QuestDefinition> = self
+            .definitions
+            .iter()
+            .filter(|definition| definition.is_daily && definition.prerequisites_met(completed))
+            .collect();
+
+        match pool.choose_multiple_weighted(rng, self.daily_quest_count, |definition| definition.weight) {
+            Ok(chosen) => chosen.copied().collect(),
+            Err(err) => {
+                println!("每日任务权重无效: {}", err);
+                Vec::new()
+            }
+        }
+    }
+
+    // One-off quests become available once, as soon as their prerequisites are met
+    pub fn unlocked_one_offs<'a>(&'a self, active: &'a [Quest], completed: &'a [Quest]) -> impl Iterator<Item = &'a QuestDefinition> {
+        self.definitions.iter().filter(move |definition| {
+            !definition.is_daily
+                && definition.prerequisites_met(completed)
+                && !active.iter().chain(completed).any(|quest| quest.id == definition.id)
+        })
+    }
+}
+
+// One way to finish a chain stage; when a stage has several, the first one done picks the branch
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChainObjective {
+    pub description: LocalizedText,
+    pub quest_type: QuestType,
+    pub required_amount: u32,
+    #[serde(default)]
+    pub target_animal: Option<AnimalType>,
+    #[serde(default)]
+    pub target_item: Option<ItemType>,
+    pub reward: QuestReward,
+    #[serde(default)]
+    pub next: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChainStage {
+    pub id: String,
+    pub title: LocalizedText,
+    pub objectives: Vec<ChainObjective>,
+    #[serde(default)]
+    pub deadline_days: Option<u32>,
+    // Stage entered when the deadline passes; None fails the whole chain
+    #[serde(default)]
+    pub on_expire: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ChainUnlock {
+    pub min_day: u32,
+    // Some animal of this type must have at least this much friendship
+    pub friendship: Vec<(AnimalType, u32)>,
+    pub completed_chains: Vec<String>,
+}
+
+impl ChainUnlock {
+    pub fn is_met(&self, day: u32, best_friendship: &HashMap<AnimalType, u32>, quest_manager: &QuestManager) -> bool {
+        day >= self.min_day
+            && self.friendship.iter().all(|(animal_type, level)| {
+                best_friendship.get(animal_type).is_some_and(|best| best >= level)
+            })
+            && self.completed_chains.iter().all(|id| {
+                quest_manager.chain_status(id) == Some(ChainStatus::Completed)
+            })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuestChainDefinition {
+    pub id: String,
+    pub title: LocalizedText,
+    #[serde(default)]
+    pub unlock: ChainUnlock,
+    pub stages: Vec<ChainStage>,
+}
+
+impl QuestChainDefinition {
+    pub fn stage(&self, stage_id: &str) -> Option<&ChainStage> {
+        self.stages.iter().find(|stage| stage.id == stage_id)
+    }
+
+    // Every objective of a stage becomes its own quest so progress and rewards work like any other
+    pub fn stage_quests(&self, stage: &ChainStage, day: u32) -> Vec<Quest> {
+        let title = format!("{} - {}", self.title.get(DEFAULT_LOCALE), stage.title.get(DEFAULT_LOCALE));
+        stage
+            .objectives
+            .iter()
+            .enumerate()
+            .map(|(index, objective)| Quest {
+                id: format!("{}/{}/{}", self.id, stage.id, index),
+                title: title.clone(),
+                description: objective.description.get(DEFAULT_LOCALE).to_string(),
+                quest_type: objective.quest_type,
+                required_amount: objective.required_amount,
+                progress: 0,
+                reward: objective.reward.clone(),
+                is_daily: false,
+                deadline: stage.deadline_days.map(|days| day + days),
+                target_animal: objective.target_animal,
+                target_item: objective.target_item,
+                penalty: None,
+                chain: Some(QuestChainLink {
+                    chain_id: self.id.clone(),
+                    stage_id: stage.id.clone(),
+                    next_stage: objective.next.clone(),
+                }),
+                involved_animals: Vec::new(),
+            })
+            .collect()
+    }
+}
+
+// Layout of assets/data/quest_chains.ron
+#[derive(Deserialize)]
+pub struct QuestChainDataFile {
+    pub chains: Vec<QuestChainDefinition>,
+}
+
+#[derive(Resource)]
+pub struct QuestChainCatalog {
+    pub chains: Vec<QuestChainDefinition>,
+}
+
+impl QuestChainCatalog {
+    pub fn new(data: QuestChainDataFile) -> Self {
+        Self { chains: data.chains }
+    }
+
+    pub fn get(&self, chain_id: &str) -> Option<&QuestChainDefinition> {
+        self.chains.iter().find(|chain| chain.id == chain_id)
+    }
+}
+
+// Issued quests keep their text in the default locale, which is also what saves store;
+// the UI looks the translation up again by id so switching language updates them too
+pub fn localized_quest_text(
+    quest: &Quest,
+    catalog: &QuestCatalog,
+    chain_catalog: &QuestChainCatalog,
+    locale: &str,
+) -> (String, String) {
+    let translated = match &quest.chain {
+        Some(link) => chain_catalog.get(&link.chain_id).and_then(|chain| {
+            let stage = chain.stage(&link.stage_id)?;
+            let index: usize = quest.id.rsplit('/').next()?.parse().ok()?;
+            let objective = stage.objectives.get(index)?;
+            Some((
+                format!("{} - {}", chain.title.get(locale), stage.title.get(locale)),
+                objective.description.get(locale).to_string(),
+            ))
+        }),
+        None => catalog.definitions.iter().find(|definition| definition.id == quest.id).map(|definition| {
+            (definition.title.get(locale).to_string(), definition.description.get(locale).to_string())
+        }),
+    };
+    translated.unwrap_or_else(|| (quest.title.clone(), quest.description.clone()))
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use super::{AnimalType, ItemType, LocalizedText, DEFAULT_LOCALE};
+
+// Something an animal produces on a fixed interval, e.g. eggs every 300s
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpeciesProduction {
+    pub item: ItemType,
+    // Game minutes between productions
+    pub interval_minutes: f32,
+    // Production speed multiplier below COLD_TEMPERATURE
+    #[serde(default = "default_cold_rate")]
+    pub cold_rate: f32,
+}
+
+fn default_cold_rate() -> f32 {
+    1.0
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpeciesDefinition {
+    pub animal_type: AnimalType,
+    pub name: LocalizedText,
+    pub personality: LocalizedText,
+    pub favorite_food: ItemType,
+    pub follow_friendship: u32,
+    pub speed: f32,
+    pub wander_distance: f32,
+    pub wander_duration: (f32, f32),
+    pub rest_duration: (f32, f32),
+    pub eat_duration: (f32, f32),
+    pub roam_radius: f32,
+    pub production: Option<SpeciesProduction>,
+    pub spawn_count: (u32, u32),
+    // Can cross ponds; everyone else walks around water
+    #[serde(default)]
+    pub swims: bool,
+}
+
+impl SpeciesDefinition {
+    fn placeholder(animal_type: AnimalType) -> Self {
+        Self {
+            animal_type,
+            name: LocalizedText(HashMap::from([(DEFAULT_LOCALE.to_string(), format!("{:?}", animal_type))])),
+            personality: LocalizedText::default(),
+            favorite_food: ItemType::Apple,
+            follow_friendship: 5,
+            speed: 50.0,
+            wander_distance: 120.0,
+            wander_duration: (3.0, 7.0),
+            rest_duration: (2.0, 6.0),
+            eat_duration: (2.0, 5.0),
+            roam_radius: 500.0,
+            production: None,
+            spawn_count: (0, 0),
+            swims: false,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.get(DEFAULT_LOCALE)
+    }
+}
+
+// Layout of assets/data/species.ron
+#[derive(Deserialize)]
+pub struct SpeciesDataFile {
+    pub species: Vec<SpeciesDefinition>,
+}
+
+#[derive(Resource)]
+pub struct SpeciesRegistry {
+    definitions: HashMap<AnimalType, SpeciesDefinition>,
+}
+
+impl SpeciesRegistry {
+    pub fn new(data: SpeciesDataFile) -> Self {
+        let mut definitions = HashMap::new();
+        for definition in data.species {
+            if definitions.insert(definition.animal_type, definition).is_some() {
+                println!("动物数据重复定义，使用最后一项");
+            }
+        }
+
+        // Species without data still behave sensibly but are never spawned
+        for animal_type in AnimalType::ALL {
+            definitions.entry(animal_type).or_insert_with(|| {
+                println!("动物 {:?} 缺少数据定义", animal_type);
+                SpeciesDefinition::placeholder(animal_type)
+            });
+        }
+
+        Self { definitions }
+    }
+
+    pub fn get(&self, animal_type: AnimalType) -> &SpeciesDefinition {
+        &self.definitions[&animal_type]
+    }
+
+    pub fn name(&self, animal_type: AnimalType) -> &str {
+        self.get(animal_type).name()
+    }
+}
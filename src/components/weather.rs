@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use serde::Deserialize;
+use std::collections::HashMap;
+use super::{Season, WeatherType};
+
+// Temperatures and transition weights for one season
+#[derive(Clone, Debug, Deserialize)]
+pub struct SeasonClimate {
+    // Average temperature over a day, in °C
+    pub base_temperature: f32,
+    // Difference between the afternoon high and the early morning low
+    pub daily_swing: f32,
+    // Weighted chance of each weather following the current one
+    pub transitions: HashMap<WeatherType, Vec<(WeatherType, u32)>>,
+}
+
+// Layout of assets/data/weather.ron
+#[derive(Deserialize)]
+pub struct WeatherDataFile {
+    pub change_interval_hours: u32,
+    pub temperature_offsets: HashMap<WeatherType, f32>,
+    pub seasons: HashMap<Season, SeasonClimate>,
+}
+
+// Tomorrow's most likely weather, recomputed whenever the weather changes
+#[derive(Clone, Copy, Debug)]
+pub struct WeatherForecast {
+    pub weather: WeatherType,
+    pub chance: f32,
+    pub high: f32,
+    pub low: f32,
+}
+
+#[derive(Resource)]
+pub struct Climate {
+    pub change_interval_hours: u32,
+    temperature_offsets: HashMap<WeatherType, f32>,
+    seasons: HashMap<Season, SeasonClimate>,
+}
+
+impl Climate {
+    pub fn new(data: WeatherDataFile) -> Self {
+        for season in Season::ALL {
+            if !data.seasons.contains_key(&season) {
+                println!("天气数据缺少{}季，该季节保持当前天气", season.name());
+            }
+        }
+
+        Self {
+            change_interval_hours: data.change_interval_hours.clamp(1, 24),
+            temperature_offsets: data.temperature_offsets,
+            seasons: data.seasons,
+        }
+    }
+
+    fn transitions(&self, season: Season, current: WeatherType) -> &[(WeatherType, u32)] {
+        self.seasons
+            .get(&season)
+            .and_then(|climate| climate.transitions.get(&current))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    // One step of the Markov chain; weather without transitions just stays as it is
+    pub fn next_weather(&self, rng: &mut impl Rng, season: Season, current: WeatherType) -> WeatherType {
+        self.transitions(season, current)
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .map(|(weather, _)| *weather)
+            .unwrap_or(current)
+    }
+
+    // Daily sine curve peaking at 15:00 and bottoming out at 03:00, shifted by the weather
+    pub fn temperature(&self, season: Season, hour: f32, weather: WeatherType) -> f32 {
+        let Some(climate) = self.seasons.get(&season) else {
+            return 15.0;
+        };
+        let phase = (hour - 9.0) / 24.0 * std::f32::consts::TAU;
+        let offset = self.temperature_offsets.get(&weather).copied().unwrap_or(0.0);
+        climate.base_temperature + climate.daily_swing / 2.0 * phase.sin() + offset
+    }
+
+    // Propagates the chance of each weather through every change left until tomorrow noon
+    pub fn forecast(&self, current: WeatherType, day: u32, hour: u32) -> WeatherForecast {
+        let mut chances: HashMap<WeatherType, f32> = HashMap::from([(current, 1.0)]);
+
+        // Hours counted from the start of day 1
+        let tomorrow_noon = day * 24 + 12;
+        let mut step_hour = (day - 1) * 24 + hour;
+        step_hour += self.change_interval_hours - step_hour % self.change_interval_hours;
+        while step_hour <= tomorrow_noon {
+            let season = Season::for_day(step_hour / 24 + 1);
+            let mut next: HashMap<WeatherType, f32> = HashMap::new();
+            for (&weather, &chance) in &chances {
+                let transitions = self.transitions(season, weather);
+                let total: u32 = transitions.iter().map(|(_, weight)| weight).sum();
+                if total == 0 {
+                    *next.entry(weather).or_insert(0.0) += chance;
+                    continue;
+                }
+                for &(to, weight) in transitions {
+                    *next.entry(to).or_insert(0.0) += chance * weight as f32 / total as f32;
+                }
+            }
+            chances = next;
+            step_hour += self.change_interval_hours;
+        }
+
+        let (weather, chance) = chances
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((current, 1.0));
+        let season = Season::for_day(day + 1);
+        WeatherForecast {
+            weather,
+            chance,
+            high: self.temperature(season, 15.0, weather),
+            low: self.temperature(season, 3.0, weather),
+        }
+    }
+}
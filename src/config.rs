@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fs;
+use crate::components::{BindingsFile, InputBindings, WorldSeed, BASE_TIME_SCALE, DEFAULT_LOCALE};
+use crate::data::load_data_file;
+
+pub const CONFIG_FILE_PATH: &str = "config/game.ron";
+pub const BINDINGS_FILE_PATH: &str = "config/bindings.ron";
+
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        let config = GameConfig::load(CONFIG_FILE_PATH);
+        let seed = resolve_world_seed(seed_from_args(std::env::args()), config.seed);
+        let bindings: BindingsFile = load_data_file(BINDINGS_FILE_PATH, include_str!("../config/bindings.ron"));
+
+        app.insert_resource(seed)
+           .insert_resource(config)
+           .insert_resource(InputBindings::new(bindings));
+    }
+}
+
+#[derive(Resource, Deserialize, Debug)]
+#[serde(default)]
+pub struct GameConfig {
+    pub seed: Option<u64>,
+    // Clock speed presets in game minutes per real second, cycled with [ and ]
+    pub time_speeds: Vec<f32>,
+    // Toggled with F8 to fast-forward while testing
+    pub debug_time_speed: f32,
+    // UI language at launch; can be switched in the settings menu
+    pub locale: String,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            time_speeds: vec![30.0, BASE_TIME_SCALE, 120.0, 240.0],
+            debug_time_speed: 1440.0,
+            locale: DEFAULT_LOCALE.to_string(),
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn load(path: &str) -> Self {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => return Self::default(),
+        };
+
+        match ron::from_str(&contents) {
+            Ok(config) => config,
+            Err(err) => {
+                println!("配置文件 {} 格式错误，使用默认配置: {}", path, err);
+                Self::default()
+            }
+        }
+    }
+}
+
+// Command line wins over the config file; with neither, every launch gets a fresh seed
+fn resolve_world_seed(arg_seed: Option<u64>, config_seed: Option<u64>) -> WorldSeed {
+    let seed = match (arg_seed, config_seed) {
+        (Some(seed), _) => WorldSeed(seed),
+        (None, Some(seed)) => WorldSeed(seed),
+        (None, None) => WorldSeed::random(),
+    };
+    println!("世界种子: {}", seed.0);
+    seed
+}
+
+// Accepts both `--seed 42` and `--seed=42`
+fn seed_from_args(args: impl Iterator<Item = String>) -> Option<u64> {
+    let mut args = args.skip(1);
+    while let Some(arg) = args.next() {
+        let value = if arg == "--seed" {
+            args.next()
+        } else {
+            arg.strip_prefix("--seed=").map(str::to_string)
+        };
+
+        if let Some(value) = value {
+            match value.parse() {
+                Ok(seed) => return Some(seed),
+                Err(_) => println!("无效的种子参数: {}", value),
+            }
+        }
+    }
+    None
+}
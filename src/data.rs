@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use std::fs;
+use crate::components::*;
+use crate::config::GameConfig;
+
+pub const ITEMS_FILE_PATH: &str = "assets/data/items.ron";
+pub const SPECIES_FILE_PATH: &str = "assets/data/species.ron";
+pub const QUESTS_FILE_PATH: &str = "assets/data/quests.ron";
+pub const QUEST_CHAINS_FILE_PATH: &str = "assets/data/quest_chains.ron";
+pub const WEATHER_FILE_PATH: &str = "assets/data/weather.ron";
+pub const ZH_CN_LOCALE_FILE_PATH: &str = "assets/locales/zh-CN.ron";
+pub const EN_LOCALE_FILE_PATH: &str = "assets/locales/en.ron";
+
+pub struct DataPlugin;
+
+impl Plugin for DataPlugin {
+    fn build(&self, app: &mut App) {
+        let items: ItemDataFile = load_data_file(ITEMS_FILE_PATH, include_str!("../assets/data/items.ron"));
+        let species: SpeciesDataFile = load_data_file(SPECIES_FILE_PATH, include_str!("../assets/data/species.ron"));
+        let quests: QuestDataFile = load_data_file(QUESTS_FILE_PATH, include_str!("../assets/data/quests.ron"));
+        let quest_chains: QuestChainDataFile = load_data_file(QUEST_CHAINS_FILE_PATH, include_str!("../assets/data/quest_chains.ron"));
+        let weather: WeatherDataFile = load_data_file(WEATHER_FILE_PATH, include_str!("../assets/data/weather.ron"));
+        let locales = vec![
+            ("zh-CN".to_string(), load_data_file(ZH_CN_LOCALE_FILE_PATH, include_str!("../assets/locales/zh-CN.ron"))),
+            ("en".to_string(), load_data_file(EN_LOCALE_FILE_PATH, include_str!("../assets/locales/en.ron"))),
+        ];
+        let locale = app.world().get_resource::<GameConfig>().map(|config| config.locale.clone()).unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+
+        app.insert_resource(ItemRegistry::new(items))
+           .insert_resource(SpeciesRegistry::new(species))
+           .insert_resource(QuestCatalog::new(quests))
+           .insert_resource(QuestChainCatalog::new(quest_chains))
+           .insert_resource(Climate::new(weather))
+           .insert_resource(Localization::new(locales, &locale));
+    }
+}
+
+// Game data is read from disk so it can be tuned without recompiling; the copy
+// compiled into the binary keeps the game playable when launched from elsewhere
+pub fn load_data_file<T: DeserializeOwned>(path: &str, embedded: &str) -> T {
+    match fs::read_to_string(path) {
+        Ok(contents) => match ron::from_str(&contents) {
+            Ok(data) => return data,
+            Err(err) => println!("数据文件 {} 格式错误，使用内置数据: {}", path, err),
+        },
+        Err(_) => println!("未找到数据文件 {}，使用内置数据", path),
+    }
+
+    ron::from_str(embedded)
+        .unwrap_or_else(|err| panic!("内置数据文件 {} 无效: {}", path, err))
+}
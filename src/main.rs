@@ -4,9 +4,13 @@ mod components;
 mod traits;
 mod systems;
 mod camera;
+mod config;
+mod data;
 
 use systems::*;
 use camera::CameraPlugin;
+use config::ConfigPlugin;
+use data::DataPlugin;
 use systems::player_systems as player_base;
 use systems::player_stats_systems as player_stats;
 use systems::quest_systems as quests;
@@ -14,25 +18,68 @@ use systems::animal_production_systems as animal_production;
 use systems::visual_feedback_systems as visual_feedback;
 use systems::ui_systems as ui;
 use systems::animal_animation_systems as animal_animation;
-use components::Weather;
+use systems::save_systems as save;
+use systems::weather_effects_systems as weather_effects;
+use systems::day_summary_systems as day_summary;
+use systems::menu_systems as menu;
+use systems::input_systems as input;
+use components::{ActionState, InputDevice, GameState, Inventory, InGame, Localization, WorldSeed, QuestManager, RewardMailbox, WeatherEffects, AnimalFed, AnimalPetted, AnimalPlayedWith, ProductCollected, ItemAcquired, ItemUsed, DayChanged, SeasonChanged, HourChanged, PhaseChanged, WeatherChanged, TimeSkipped, FriendshipChanged, QuestCompleted, QuestFailed, PlayerSlept, DailyStats};
 
 // Plugin structures
+pub struct GameEventsPlugin;
+
+impl Plugin for GameEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AnimalFed>()
+           .add_event::<AnimalPetted>()
+           .add_event::<AnimalPlayedWith>()
+           .add_event::<ProductCollected>()
+           .add_event::<ItemAcquired>()
+           .add_event::<ItemUsed>()
+           .add_event::<DayChanged>()
+           .add_event::<SeasonChanged>()
+           .add_event::<HourChanged>()
+           .add_event::<PhaseChanged>()
+           .add_event::<WeatherChanged>()
+           .add_event::<TimeSkipped>()
+           .add_event::<FriendshipChanged>()
+           .add_event::<QuestCompleted>()
+           .add_event::<QuestFailed>()
+           .add_event::<PlayerSlept>();
+    }
+}
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActionState>()
+           .init_resource::<InputDevice>()
+           .add_systems(Startup, input::InputSystems::report_binding_conflicts)
+           .add_systems(PreUpdate, (
+                input::InputSystems::update_action_state,
+                input::InputSystems::detect_input_device,
+           ).after(bevy::input::InputSystem));
+    }
+}
+
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, (
+        app.add_systems(OnEnter(GameState::Loading), (
                 player_base::PlayerSystems::spawn_player,
                 player_stats::PlayerSystems::setup_player_stats,
             ))
            .add_systems(Update, (
                 player_base::PlayerSystems::player_movement,
+                player_base::PlayerSystems::trigger_tool_animation,
                 player_base::PlayerSystems::animate_player,
                 player_stats::PlayerSystems::update_player_stats,
                 player_stats::PlayerSystems::handle_player_eating,
                 player_stats::PlayerSystems::handle_player_sleeping,
                 player_stats::PlayerSystems::display_player_stats,
-            ));
+            ).run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -40,20 +87,23 @@ pub struct AnimalsPlugin;
 
 impl Plugin for AnimalsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, animal_systems::AnimalSystems::spawn_animals)
+        app.add_systems(OnEnter(GameState::Loading), animal_systems::AnimalSystems::spawn_animals.run_if(not(resource_exists::<save::LoadedSave>)))
            .add_systems(Update, (
                 animal_systems::AnimalSystems::animal_movement,
                 animal_systems::AnimalSystems::animal_idle_behavior,
-                animal_systems::AnimalSystems::keep_animals_in_bounds,
+                animal_systems::AnimalSystems::keep_animals_in_bounds
+                    .after(animal_systems::AnimalSystems::animal_idle_behavior)
+                    .before(animal_systems::AnimalSystems::animal_movement),
                 animal_systems::AnimalSystems::debug_animal_behavior,
                 animal_animation::AnimalAnimationSystems::update_animal_animations,
                 animal_animation::update_feeding_effects,
                 animal_animation::update_heart_effects,
-                animal_production::AnimalProductionSystems::update_animal_production,
+                // Reads the night skipped by sleeping before the day summary pauses the game
+                animal_production::AnimalProductionSystems::update_animal_production.after(player_stats::PlayerSystems::handle_player_sleeping),
                 animal_production::AnimalProductionSystems::handle_production_collection,
                 animal_production::AnimalProductionSystems::update_production_indicators,
                 animal_production::AnimalProductionSystems::enhance_animal_spawning,
-            ));
+            ).run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -64,8 +114,8 @@ impl Plugin for InteractionPlugin {
         app.add_systems(Update, (
                 InteractionSystems::handle_player_interactions,
                 InteractionSystems::show_interaction_prompts,
-                InteractionSystems::update_animal_behavior,
-            ));
+                InteractionSystems::update_animal_behavior.after(player_stats::PlayerSystems::handle_player_sleeping),
+            ).run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -73,23 +123,38 @@ pub struct TimeOfDayPlugin;
 
 impl Plugin for TimeOfDayPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, (
+        app.add_systems(OnEnter(GameState::Loading), (
                 TimeSystems::setup_time_of_day,
                 quests::QuestSystems::setup_quest_manager,
                 setup_weather,
             ))
+           .init_resource::<RewardMailbox>()
            .add_systems(Update, (
-                TimeSystems::update_time_of_day,
-                TimeSystems::apply_time_visual_effects,
-                TimeSystems::update_animal_behavior_by_time,
-                TimeSystems::display_time_info,
-                update_weather_system,
-                quests::QuestSystems::initialize_daily_quests,
-                quests::QuestSystems::track_feeding_quests,
-                quests::QuestSystems::track_interaction_quests,
-                quests::QuestSystems::check_quest_completion,
-                quests::QuestSystems::display_quests,
-            ));
+                (
+                    TimeSystems::handle_time_controls,
+                    TimeSystems::update_time_of_day,
+                ).chain(),
+                (
+                    TimeSystems::emit_time_events,
+                    TimeSystems::announce_calendar_changes,
+                    TimeSystems::display_time_info,
+                ).chain()
+                    .after(TimeSystems::update_time_of_day)
+                    .after(player_stats::PlayerSystems::handle_player_sleeping),
+                TimeSystems::update_day_night_tint,
+                TimeSystems::update_animal_behavior_by_time.after(update_weather_system),
+                update_weather_system.after(TimeSystems::emit_time_events),
+                update_temperature.after(player_stats::PlayerSystems::handle_player_sleeping),
+                quests::QuestSystems::roll_over_day
+                    .after(TimeSystems::update_time_of_day)
+                    .after(player_stats::PlayerSystems::handle_player_sleeping),
+                (
+                    quests::QuestSystems::unlock_quest_chains,
+                    quests::QuestSystems::track_quest_events,
+                    quests::QuestSystems::check_quest_completion,
+                ).chain(),
+                quests::QuestSystems::claim_mailbox,
+            ).run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -97,13 +162,13 @@ pub struct InventoryPlugin;
 
 impl Plugin for InventoryPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, InventorySystems::setup_inventory)
+        app.add_systems(OnEnter(GameState::Loading), InventorySystems::setup_inventory.run_if(not(resource_exists::<save::LoadedSave>)))
            .add_systems(Update, (
                 InventorySystems::toggle_inventory,
                 InventorySystems::handle_inventory_input,
                 InventorySystems::use_selected_item,
                 InventorySystems::show_inventory_status,
-            ));
+            ).run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -111,7 +176,8 @@ pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, WorldSystems::spawn_world);
+        app.add_systems(OnEnter(GameState::Loading), WorldSystems::spawn_world)
+           .add_systems(Update, WorldSystems::water_plants.after(player_stats::PlayerSystems::handle_player_sleeping).run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -122,7 +188,45 @@ impl Plugin for VisualFeedbackPlugin {
         app.add_systems(Update, (
             visual_feedback::VisualFeedbackSystems::update_interaction_effects,
             visual_feedback::VisualFeedbackSystems::update_floating_text,
-        ));
+        ).run_if(in_state(GameState::Playing)));
+    }
+}
+
+pub struct WeatherEffectsPlugin;
+
+impl Plugin for WeatherEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WeatherEffects>()
+           .add_systems(Startup, weather_effects::WeatherEffectsSystems::setup_weather_effects)
+           .add_systems(Update, (
+                weather_effects::WeatherEffectsSystems::update_weather_effects.after(update_weather_system).after(update_temperature),
+                weather_effects::WeatherEffectsSystems::animate_weather_particles,
+                weather_effects::WeatherEffectsSystems::update_cloud_shadows,
+                weather_effects::WeatherEffectsSystems::apply_snow_cover,
+                weather_effects::WeatherEffectsSystems::update_puddles.run_if(resource_changed::<WeatherEffects>),
+            ).run_if(in_state(GameState::Playing)));
+    }
+}
+
+pub struct DaySummaryPlugin;
+
+impl Plugin for DaySummaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DailyStats>()
+           .init_resource::<day_summary::DaySummary>()
+           .add_systems(Startup, day_summary::DaySummarySystems::setup_day_summary_ui)
+           .add_systems(Update, (
+                (
+                    day_summary::DaySummarySystems::record_daily_stats,
+                    day_summary::DaySummarySystems::open_day_summary,
+                ).chain()
+                    .after(quests::QuestSystems::roll_over_day)
+                    .after(quests::QuestSystems::check_quest_completion)
+                    .after(update_weather_system)
+                    .run_if(in_state(GameState::Playing)),
+                day_summary::DaySummarySystems::handle_day_summary_input.run_if(in_state(GameState::DayTransition)),
+                day_summary::DaySummarySystems::update_day_summary_fade.run_if(in_state(InGame)),
+            ));
     }
 }
 
@@ -130,20 +234,73 @@ pub struct UIPlugin;
 
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, ui::UISystems::setup_ui)
+        app.init_resource::<ui::QuestJournal>()
+           .add_systems(PreStartup, ui::UISystems::setup_ui_font)
+           .add_systems(Startup, (ui::UISystems::setup_ui, ui::UISystems::setup_quest_ui))
+           .add_systems(Update, (
+                ui::UISystems::update_localized_labels.run_if(resource_changed::<Localization>),
+                ui::UISystems::update_prompt_labels,
+           ))
            .add_systems(Update, (
                 ui::UISystems::update_player_status_ui,
                 ui::UISystems::update_inventory_ui,
                 ui::UISystems::update_time_weather_ui,
-                ui::UISystems::handle_inventory_input,
+                ui::UISystems::highlight_inventory_selection.run_if(resource_changed::<Inventory>),
+                ui::UISystems::update_seed_ui.run_if(resource_changed::<WorldSeed>.or(resource_changed::<Localization>)),
+                (
+                    ui::UISystems::handle_quest_journal_input.run_if(in_state(GameState::Playing)),
+                    (
+                        ui::UISystems::update_quest_journal,
+                        ui::UISystems::update_quest_tracker,
+                    ).run_if(resource_changed::<QuestManager>.or(resource_changed::<ui::QuestJournal>).or(resource_changed::<Localization>).or(resource_changed::<InputDevice>)),
+                ).chain(),
+            ).run_if(in_state(InGame)));
+    }
+}
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<GameState>()
+           .add_computed_state::<InGame>()
+           .init_resource::<menu::MenuPage>()
+           .init_resource::<menu::MenuSelection>()
+           .add_systems(Update, (
+                (
+                    menu::MenuSystems::rebuild_menu.run_if(state_changed::<GameState>.or(resource_changed::<menu::MenuPage>).or(resource_changed::<Localization>)),
+                    menu::MenuSystems::handle_menu_input.run_if(in_state(GameState::Title).or(in_state(GameState::Paused))),
+                ).chain(),
+                menu::MenuSystems::toggle_pause.run_if(in_state(GameState::Playing).or(in_state(GameState::Paused))),
             ));
     }
 }
 
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<save::SaveGameEvent>()
+           .add_systems(Update, (
+                save::SaveSystems::restore_saved_game.run_if(resource_exists::<save::LoadedSave>),
+                menu::MenuSystems::finish_loading,
+            ).chain().run_if(in_state(GameState::Loading)))
+           .add_systems(Update, (
+                save::SaveSystems::handle_save_input.run_if(in_state(GameState::Playing)),
+                save::SaveSystems::save_game,
+            ).chain().after(quests::QuestSystems::roll_over_day).run_if(in_state(InGame)));
+    }
+}
+
 fn main() {
     App::new()
         .insert_resource(ClearColor(Color::srgb(0.5, 0.8, 1.0)))
         .add_plugins(DefaultPlugins)
+        .add_plugins(ConfigPlugin)
+        .add_plugins(DataPlugin)
+        .add_plugins(GameEventsPlugin)
+        .add_plugins(MenuPlugin)
+        .add_plugins(InputPlugin)
         .add_plugins(PlayerPlugin)
         .add_plugins(CameraPlugin)
         .add_plugins(AnimalsPlugin)
@@ -152,6 +309,9 @@ fn main() {
         .add_plugins(InventoryPlugin)
         .add_plugins(WorldPlugin)
         .add_plugins(VisualFeedbackPlugin)
+        .add_plugins(WeatherEffectsPlugin)
+        .add_plugins(DaySummaryPlugin)
         .add_plugins(UIPlugin)
+        .add_plugins(SavePlugin)
         .run();
 }
\ No newline at end of file
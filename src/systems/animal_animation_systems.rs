@@ -7,8 +7,9 @@ impl AnimalAnimationSystems {
     pub fn spawn_animal_with_animation(
         commands: &mut Commands,
         position: Vec3,
-        animal_type: AnimalType,
+        species: &SpeciesDefinition,
     ) -> Entity {
+        let animal_type = species.animal_type;
         let mut entity_commands = commands.spawn_empty();
         
         match animal_type {
@@ -695,7 +696,7 @@ impl AnimalAnimationSystems {
             Transform::from_xyz(position.x, position.y, position.z),
             Animal {
                 animal_type,
-                speed: 50.0,
+                speed: species.speed,
                 friendship_level: 0,
                 hunger: 30.0,
             },
@@ -714,9 +715,9 @@ impl AnimalAnimationSystems {
                 facing_left: false,
             },
             Interactable {
-                interaction_type: InteractionType::Feed,
                 interaction_range: 40.0,
             },
+            Collider::new(Vec2::new(16.0, 10.0)),
         )).id()
     }
 
@@ -7,12 +7,21 @@ pub struct AnimalProductionSystems;
 impl AnimalProductionSystems {
     pub fn update_animal_production(
         time: Res<Time>,
+        time_of_day: Res<TimeOfDay>,
+        mut skipped_events: EventReader<TimeSkipped>,
         mut query: Query<(Entity, &mut AnimalProduction, &Animal)>,
         mut commands: Commands,
+        species_registry: Res<SpeciesRegistry>,
+        weather: Res<Weather>,
     ) {
+        let minutes = time_of_day.elapsed_minutes(&time, &mut skipped_events);
+        let cold = weather.temperature < COLD_TEMPERATURE;
         for (entity, mut production, animal) in query.iter_mut() {
-            if production.update(time.delta()) {
-                println!("{} 可以生产了！", Self::get_animal_name(animal.animal_type));
+            let rate = species_registry.get(animal.animal_type).production.as_ref()
+                .filter(|_| cold)
+                .map_or(1.0, |production| production.cold_rate);
+            if production.update(std::time::Duration::from_secs_f32(minutes * rate)) {
+                println!("{} 可以生产了！", species_registry.name(animal.animal_type));
                 
                 // Add visual indicator that animal can produce
                 commands.entity(entity).insert(ProductionIndicator {
@@ -22,14 +31,19 @@ impl AnimalProductionSystems {
         }
     }
     
+    #[allow(clippy::too_many_arguments)]
     pub fn handle_production_collection(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         mut query: Query<(Entity, &Transform, &mut AnimalProduction, &Animal, Option<&ProductionIndicator>)>,
         player_query: Query<&Transform, With<Player>>,
         mut commands: Commands,
         mut inventory: ResMut<Inventory>,
+        item_registry: Res<ItemRegistry>,
+        species_registry: Res<SpeciesRegistry>,
+        mut collected_events: EventWriter<ProductCollected>,
+        mut acquired_events: EventWriter<ItemAcquired>,
     ) {
-        if !keyboard.just_pressed(KeyCode::KeyF) {
+        if !actions.just_pressed(InputAction::Collect) {
             return;
         }
         
@@ -46,17 +60,10 @@ impl AnimalProductionSystems {
             
             if distance <= 50.0 && production.can_produce {
                 if let Some(item_type) = production.collect_production() {
-                    let item = Item {
-                        item_type,
-                        quantity: 1,
-                        name: Self::get_item_name(item_type),
-                        category: Self::get_item_category(item_type),
-                        value: Self::get_item_value(item_type),
-                        description: Self::get_item_description(item_type),
-                    };
-                    
-                    if inventory.add_item(item) {
-                        println!("收集了 {} 的 {}！", Self::get_animal_name(animal.animal_type), Self::get_item_name(item_type));
+                    if inventory.add_item(Item::new(item_type, 1)) {
+                        println!("收集了 {} 的 {}！", species_registry.name(animal.animal_type), item_registry.name(item_type));
+                        collected_events.write(ProductCollected { entity, animal_type: animal.animal_type, item_type });
+                        acquired_events.write(ItemAcquired { item_type, quantity: 1 });
                         
                         // Remove production indicator
                         if indicator.is_some() {
@@ -88,63 +95,17 @@ impl AnimalProductionSystems {
         mut commands: Commands,
         animal_query: Query<(Entity, &Animal)>,
         production_query: Query<Entity, With<AnimalProduction>>,
+        species_registry: Res<SpeciesRegistry>,
     ) {
         // Add production components to existing animals that don't have them
         let has_production_entities: HashSet<Entity> = production_query.iter().collect();
         
         for (entity, animal) in animal_query.iter() {
             if !has_production_entities.contains(&entity) {
-                commands.entity(entity).insert(AnimalProduction::new(animal.animal_type));
+                commands.entity(entity).insert(AnimalProduction::new(species_registry.get(animal.animal_type)));
             }
         }
     }
-    
-    fn get_animal_name(animal_type: AnimalType) -> &'static str {
-        match animal_type {
-            AnimalType::Dog => "小狗",
-            AnimalType::Cat => "小猫",
-            AnimalType::Chicken => "小鸡",
-            AnimalType::Cow => "奶牛",
-            AnimalType::Sheep => "小羊",
-            AnimalType::Pig => "小猪",
-            AnimalType::Duck => "小鸭",
-            AnimalType::Horse => "小马",
-        }
-    }
-    
-    fn get_item_name(item_type: ItemType) -> String {
-        match item_type {
-            ItemType::Egg => "鸡蛋".to_string(),
-            ItemType::Milk => "牛奶".to_string(),
-            ItemType::Wool => "羊毛".to_string(),
-            _ => "未知物品".to_string(),
-        }
-    }
-    
-    fn get_item_category(item_type: ItemType) -> ItemCategory {
-        match item_type {
-            ItemType::Egg | ItemType::Milk | ItemType::Wool => ItemCategory::Special,
-            _ => ItemCategory::Food,
-        }
-    }
-    
-    fn get_item_value(item_type: ItemType) -> u32 {
-        match item_type {
-            ItemType::Egg => 8,
-            ItemType::Milk => 10,
-            ItemType::Wool => 20,
-            _ => 1,
-        }
-    }
-    
-    fn get_item_description(item_type: ItemType) -> String {
-        match item_type {
-            ItemType::Egg => "新鲜的鸡蛋".to_string(),
-            ItemType::Milk => "新鲜的牛奶".to_string(),
-            ItemType::Wool => "柔软的羊毛".to_string(),
-            _ => "普通物品".to_string(),
-        }
-    }
 }
 
 #[derive(Component)]
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::ui_systems::{PromptLabel, QuestText};
+
+// How long the screen takes to fade from black into the next morning
+const FADE_SECONDS: f32 = 1.5;
+
+pub struct DaySummarySystems;
+
+impl DaySummarySystems {
+    pub fn setup_day_summary_ui(mut commands: Commands) {
+        // Full screen backdrop, hidden until the player sleeps
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    display: Display::None,
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.0)),
+                GlobalZIndex(10),
+                DaySummaryBackdrop,
+            ))
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(420.0),
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect::all(Val::Px(16.0)),
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.1, 0.08, 0.05, 0.95)),
+                        BorderColor(Color::srgb(0.8, 0.7, 0.5)),
+                        DaySummaryPanel,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(1.0, 0.95, 0.85)),
+                            DaySummaryText,
+                        ));
+                        parent.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(1.0, 0.95, 0.85)),
+                            Node {
+                                margin: UiRect::top(Val::Px(16.0)),
+                                ..default()
+                            },
+                            PromptLabel {
+                                key: "summary.continue",
+                                inputs: &[("confirm", InputContext::Menu, InputAction::Confirm)],
+                            },
+                        ));
+                    });
+            });
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn record_daily_stats(
+        mut stats: ResMut<DailyStats>,
+        mut fed_events: EventReader<AnimalFed>,
+        mut petted_events: EventReader<AnimalPetted>,
+        mut played_events: EventReader<AnimalPlayedWith>,
+        mut collected_events: EventReader<ProductCollected>,
+        mut friendship_events: EventReader<FriendshipChanged>,
+        mut completed_events: EventReader<QuestCompleted>,
+        mut failed_events: EventReader<QuestFailed>,
+    ) {
+        stats.animals_fed += fed_events.read().count() as u32;
+        // Playing counts as petting in the report
+        stats.animals_petted += (petted_events.read().count() + played_events.read().count()) as u32;
+
+        for event in collected_events.read() {
+            stats.add_product(event.item_type);
+        }
+        for event in friendship_events.read() {
+            stats.add_friendship(event.animal_type, event.amount);
+        }
+        for event in completed_events.read() {
+            stats.coins_earned += event.coins;
+            stats.quests_completed.push(event.quest.clone());
+        }
+        for event in failed_events.read() {
+            stats.coins_spent += event.coins_lost;
+            stats.quests_failed.push(event.quest.clone());
+        }
+    }
+
+    // Shows the report and starts a fresh accumulator for the new day
+    #[allow(clippy::too_many_arguments)]
+    pub fn open_day_summary(
+        mut slept_events: EventReader<PlayerSlept>,
+        mut stats: ResMut<DailyStats>,
+        mut next_state: ResMut<NextState<GameState>>,
+        time_of_day: Res<TimeOfDay>,
+        weather: Res<Weather>,
+        climate: Res<Climate>,
+        species_registry: Res<SpeciesRegistry>,
+        quest_text: QuestText,
+        mut text_query: Query<&mut Text, With<DaySummaryText>>,
+    ) {
+        if slept_events.read().count() == 0 {
+            return;
+        }
+
+        let stats = std::mem::take(&mut *stats);
+        let localization = &quest_text.localization;
+        let list = |entries: Vec<String>| {
+            if entries.is_empty() {
+                localization.get("summary.none").to_string()
+            } else {
+                entries.join(localization.get("list.separator"))
+            }
+        };
+
+        let products = stats.products.iter()
+            .map(|(item_type, count)| format!("{}x{}", localization.text(&quest_text.item_registry.get(*item_type).name), count))
+            .collect();
+        let friendship = stats.friendship.iter()
+            .map(|(animal_type, amount)| format!("{}+{}", localization.text(&species_registry.get(*animal_type).name), amount))
+            .collect();
+        let quests_completed = stats.quests_completed.iter().map(|quest| quest_text.get(quest).0).collect();
+        let quests_failed = stats.quests_failed.iter().map(|quest| quest_text.get(quest).0).collect();
+
+        // Weather has already caught up on the night, so this is the morning's weather
+        let season = time_of_day.season();
+        let low = climate.temperature(season, 3.0, weather.current_weather);
+        let high = climate.temperature(season, 15.0, weather.current_weather);
+
+        // The player always wakes up on the morning after the day being reported
+        let lines = [
+            localization.format("summary.title", &[("day", &time_of_day.day.saturating_sub(1).max(1))]),
+            String::new(),
+            localization.format("summary.coins", &[("earned", &stats.coins_earned), ("spent", &stats.coins_spent)]),
+            localization.format("summary.products", &[("list", &list(products))]),
+            localization.format("summary.fed", &[("count", &stats.animals_fed)]),
+            localization.format("summary.petted", &[("count", &stats.animals_petted)]),
+            localization.format("summary.friendship", &[("list", &list(friendship))]),
+            localization.format("summary.quests_completed", &[("list", &list(quests_completed))]),
+            localization.format("summary.quests_failed", &[("list", &list(quests_failed))]),
+            localization.format("summary.weather", &[
+                ("weather", &localization.weather_name(weather.current_weather)),
+                ("low", &format!("{:.0}", low)),
+                ("high", &format!("{:.0}", high)),
+            ]),
+        ];
+
+        if let Ok(mut text) = text_query.single_mut() {
+            **text = lines.join("\n");
+        }
+
+        // The morning waits until the report is closed
+        next_state.set(GameState::DayTransition);
+    }
+
+    pub fn handle_day_summary_input(
+        actions: Res<ActionState>,
+        mut summary: ResMut<DaySummary>,
+        mut next_state: ResMut<NextState<GameState>>,
+    ) {
+        if !actions.just_pressed(InputAction::Confirm) {
+            return;
+        }
+
+        summary.fade = Some(Timer::from_seconds(FADE_SECONDS, TimerMode::Once));
+        next_state.set(GameState::Playing);
+    }
+
+    // The report sits on a dark backdrop which turns black and fades out once closed
+    pub fn update_day_summary_fade(
+        time: Res<Time>,
+        state: Res<State<GameState>>,
+        mut summary: ResMut<DaySummary>,
+        mut backdrop_query: Query<(&mut Node, &mut BackgroundColor), With<DaySummaryBackdrop>>,
+        mut panel_query: Query<&mut Visibility, With<DaySummaryPanel>>,
+    ) {
+        let Ok((mut backdrop_node, mut backdrop_color)) = backdrop_query.single_mut() else {
+            return;
+        };
+        let Ok(mut panel_visibility) = panel_query.single_mut() else {
+            return;
+        };
+
+        if *state.get() == GameState::DayTransition {
+            backdrop_node.display = Display::Flex;
+            *panel_visibility = Visibility::Inherited;
+            backdrop_color.0 = Color::srgba(0.0, 0.0, 0.0, 0.85);
+            return;
+        }
+
+        let Some(fade) = summary.fade.as_mut() else {
+            backdrop_node.display = Display::None;
+            return;
+        };
+
+        fade.tick(time.delta());
+        *panel_visibility = Visibility::Hidden;
+        backdrop_color.0 = Color::srgba(0.0, 0.0, 0.0, 1.0 - fade.fraction());
+        if fade.finished() {
+            summary.fade = None;
+            backdrop_node.display = Display::None;
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct DaySummaryBackdrop;
+
+#[derive(Component)]
+pub struct DaySummaryPanel;
+
+#[derive(Component)]
+pub struct DaySummaryText;
+
+// Fade into the morning after the report closes
+#[derive(Resource, Default)]
+pub struct DaySummary {
+    pub fade: Option<Timer>,
+}
@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::components::*;
+
+pub struct InputSystems;
+
+impl InputSystems {
+    pub fn report_binding_conflicts(bindings: Res<InputBindings>) {
+        let conflicts = bindings.conflicts();
+        for conflict in &conflicts {
+            println!(
+                "按键冲突: {:?} 同时绑定了 {:?}/{:?} 和 {:?}/{:?}",
+                conflict.input, conflict.first.0, conflict.first.1, conflict.second.0, conflict.second.1
+            );
+        }
+        if !conflicts.is_empty() {
+            println!("请修改 config/bindings.ron 中的按键绑定");
+        }
+    }
+
+    // Resolves keys and gamepad buttons into actions once per frame, before any gameplay
+    // system reads them. Keyboard and every connected gamepad can be used at the same time.
+    pub fn update_action_state(
+        keyboard: Res<ButtonInput<KeyCode>>,
+        gamepads: Query<&Gamepad>,
+        bindings: Res<InputBindings>,
+        state: Res<State<GameState>>,
+        inventory: Option<Res<Inventory>>,
+        mut actions: ResMut<ActionState>,
+    ) {
+        actions.clear();
+
+        // Highest priority first; a key bound in one context is hidden from the ones below it
+        let inventory_open = inventory.is_some_and(|inventory| inventory.is_open);
+        let contexts: &[InputContext] = match state.get() {
+            GameState::Playing if inventory_open => &[InputContext::Global, InputContext::Inventory, InputContext::Gameplay],
+            GameState::Playing => &[InputContext::Global, InputContext::Gameplay],
+            GameState::Title | GameState::Paused | GameState::DayTransition => &[InputContext::Global, InputContext::Menu],
+            GameState::Loading => &[InputContext::Global],
+        };
+
+        let mut claimed = HashSet::new();
+        for &context in contexts {
+            for (action, inputs) in bindings.actions(context) {
+                for input in inputs.iter().filter(|input| !claimed.contains(*input)) {
+                    let (pressed, just_pressed) = match *input {
+                        InputBinding::Key(key) => (keyboard.pressed(key), keyboard.just_pressed(key)),
+                        InputBinding::Button(button) => (
+                            gamepads.iter().any(|gamepad| gamepad.pressed(button)),
+                            gamepads.iter().any(|gamepad| gamepad.just_pressed(button)),
+                        ),
+                    };
+                    if pressed {
+                        actions.press(*action, just_pressed);
+                    }
+                }
+            }
+            claimed.extend(bindings.inputs(context));
+        }
+
+        // The left stick always walks, scaled by how far it is pushed
+        if contexts.contains(&InputContext::Gameplay) {
+            let stick = gamepads.iter().map(Gamepad::left_stick).fold(Vec2::ZERO, |sum, stick| sum + stick);
+            actions.set_move_axis(stick.clamp_length_max(1.0));
+        }
+    }
+
+    // Prompts follow the last device that was used, so only switch on a deliberate input
+    pub fn detect_input_device(
+        keyboard: Res<ButtonInput<KeyCode>>,
+        gamepads: Query<&Gamepad>,
+        mut device: ResMut<InputDevice>,
+    ) {
+        let gamepad_used = gamepads.iter().any(|gamepad| {
+            gamepad.get_just_pressed().next().is_some() || gamepad.left_stick().length() > STICK_ACTIVITY_THRESHOLD
+        });
+        if gamepad_used {
+            device.set_if_neq(InputDevice::Gamepad);
+        } else if keyboard.get_just_pressed().next().is_some() {
+            device.set_if_neq(InputDevice::Keyboard);
+        }
+    }
+}
+
+const STICK_ACTIVITY_THRESHOLD: f32 = 0.5;
@@ -7,14 +7,25 @@ use crate::systems::animal_animation_systems as animal_animation;
 pub struct InteractionSystems;
 
 impl InteractionSystems {
+    // Interact feeds the animals in reach when the selected item suits them and pets them
+    // otherwise; playing has its own action
+    #[allow(clippy::too_many_arguments)]
     pub fn handle_player_interactions(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         mut commands: Commands,
-        mut query: Query<(Entity, &Transform, &mut Interactable, &mut Animal, &mut AnimalAnimation)>,
+        mut query: Query<(Entity, &Transform, &Interactable, &mut Animal, &mut AnimalAnimation)>,
         player_query: Query<&Transform, (With<Player>, Without<Animal>)>,
         mut inventory: ResMut<Inventory>,
+        item_registry: Res<ItemRegistry>,
+        species_registry: Res<SpeciesRegistry>,
+        mut petted_events: EventWriter<AnimalPetted>,
+        mut played_events: EventWriter<AnimalPlayedWith>,
+        mut fed_events: EventWriter<AnimalFed>,
+        mut used_events: EventWriter<ItemUsed>,
+        mut friendship_events: EventWriter<FriendshipChanged>,
     ) {
-        if !keyboard.just_pressed(KeyCode::Space) {
+        let playing = actions.just_pressed(InputAction::PlayWithAnimal);
+        if !playing && !actions.just_pressed(InputAction::Interact) {
             return;
         }
 
@@ -25,18 +36,25 @@ impl InteractionSystems {
 
         let player_pos = Vec2::new(player_transform.translation.x, player_transform.translation.y);
 
-        for (entity, transform, mut interactable, mut animal, mut animation) in query.iter_mut() {
+        for (entity, transform, interactable, mut animal, mut animation) in query.iter_mut() {
             let animal_pos = Vec2::new(transform.translation.x, transform.translation.y);
             let distance = (player_pos - animal_pos).length();
 
             if distance <= interactable.interaction_range {
-                Self::interact_with_animal(&mut animal, &mut inventory, &interactable.interaction_type);
+                let interaction = if playing {
+                    InteractionType::Play
+                } else {
+                    Self::interaction_for(&inventory, &item_registry, animal.animal_type)
+                };
+                Self::interact_with_animal(&mut animal, &mut inventory, &item_registry, &species_registry, &interaction);
                 
                 // Increase friendship based on interaction type
-                match interactable.interaction_type {
+                match interaction {
                     InteractionType::Pet => {
                         animal.friendship_level += 1;
-                        println!("抚摸了{}! 好感度+1", Self::get_animal_name(animal.animal_type));
+                        println!("抚摸了{}! 好感度+1", species_registry.name(animal.animal_type));
+                        petted_events.write(AnimalPetted { entity, animal_type: animal.animal_type });
+                        friendship_events.write(FriendshipChanged { animal_type: animal.animal_type, amount: 1 });
                         
                         // Trigger happy animation
                         animation.is_happy = true;
@@ -45,63 +63,63 @@ impl InteractionSystems {
                         // Add visual feedback
                         visual_feedback::VisualFeedbackSystems::show_petting_feedback(
                             &mut commands,
-                            &transform,
+                            transform,
                             animal.animal_type,
                         );
                         
                         // Spawn heart effects
                         animal_animation::AnimalAnimationSystems::trigger_happy_animation(
                             &mut commands,
-                            &transform,
+                            transform,
                         );
                     }
                     InteractionType::Feed => {
-                        let selected_item = inventory.get_selected_item().cloned();
+                        // Only chosen when the selected item suits this animal
+                        let Some(item) = inventory.get_selected_item().cloned() else {
+                            continue;
+                        };
+                        let definition = item_registry.get(item.item_type);
+                        let friendship_increase = definition.get_friendship_bonus();
+                        animal.friendship_level += friendship_increase;
+                        animal.hunger -= definition.get_hunger_reduction();
+                        animal.hunger = animal.hunger.max(0.0);
                         
-                        if let Some(item) = selected_item {
-                            if item.can_use_on_animal(animal.animal_type) {
-                                let friendship_increase = item.get_friendship_bonus();
-                                animal.friendship_level += friendship_increase;
-                                animal.hunger -= item.get_hunger_reduction();
-                                animal.hunger = animal.hunger.max(0.0);
-                                
-                                // 消耗物品
-                                inventory.remove_item(&item.item_type, 1);
-                                
-                                println!("喂食了{} {}! 好感度+{}, 饥饿度-{}", 
-                                    Self::get_animal_name(animal.animal_type),
-                                    item.name,
-                                    friendship_increase,
-                                    item.get_hunger_reduction());
-                                
-                                // Trigger feeding animation
-                                animation.is_eating = true;
-                                animation.animation_timer.reset();
-                                
-                                // Add visual feedback
-                                visual_feedback::VisualFeedbackSystems::show_feeding_feedback(
-                                    &mut commands,
-                                    &transform,
-                                    animal.animal_type,
-                                    &item.name,
-                                    friendship_increase,
-                                );
-                                
-                                // Spawn feeding effects
-                                animal_animation::AnimalAnimationSystems::trigger_feeding_animation(
-                                    &mut commands,
-                                    &transform,
-                                );
-                            } else {
-                                println!("{}不喜欢吃{}", Self::get_animal_name(animal.animal_type), item.name);
-                            }
-                        } else {
-                            println!("没有选择任何物品！");
-                        }
+                        // 消耗物品
+                        inventory.remove_item(&item.item_type, 1);
+                        fed_events.write(AnimalFed { entity, animal_type: animal.animal_type, item_type: item.item_type });
+                        used_events.write(ItemUsed { item_type: item.item_type });
+                        friendship_events.write(FriendshipChanged { animal_type: animal.animal_type, amount: friendship_increase });
+                        
+                        println!("喂食了{} {}! 好感度+{}, 饥饿度-{}", 
+                            species_registry.name(animal.animal_type),
+                            definition.name(),
+                            friendship_increase,
+                            definition.get_hunger_reduction());
+                        
+                        // Trigger feeding animation
+                        animation.is_eating = true;
+                        animation.animation_timer.reset();
+                        
+                        // Add visual feedback
+                        visual_feedback::VisualFeedbackSystems::show_feeding_feedback(
+                            &mut commands,
+                            transform,
+                            animal.animal_type,
+                            definition.name(),
+                            friendship_increase,
+                        );
+                        
+                        // Spawn feeding effects
+                        animal_animation::AnimalAnimationSystems::trigger_feeding_animation(
+                            &mut commands,
+                            transform,
+                        );
                     }
                     InteractionType::Play => {
                         animal.friendship_level += 2;
-                        println!("和{}玩耍! 好感度+2", Self::get_animal_name(animal.animal_type));
+                        println!("和{}玩耍! 好感度+2", species_registry.name(animal.animal_type));
+                        played_events.write(AnimalPlayedWith { entity, animal_type: animal.animal_type });
+                        friendship_events.write(FriendshipChanged { animal_type: animal.animal_type, amount: 2 });
                         
                         // Trigger happy animation
                         animation.is_happy = true;
@@ -118,7 +136,7 @@ impl InteractionSystems {
                         // Spawn heart effects
                         animal_animation::AnimalAnimationSystems::trigger_happy_animation(
                             &mut commands,
-                            &transform,
+                            transform,
                         );
                     }
                 }
@@ -126,51 +144,56 @@ impl InteractionSystems {
         }
     }
 
+    // What Interact does to an animal: feed it the selected item if it eats it, otherwise pet it
+    pub fn interaction_for(inventory: &Inventory, item_registry: &ItemRegistry, animal_type: AnimalType) -> InteractionType {
+        let feedable = inventory
+            .get_selected_item()
+            .is_some_and(|item| item.quantity > 0 && item_registry.get(item.item_type).can_use_on_animal(animal_type));
+        if feedable {
+            InteractionType::Feed
+        } else {
+            InteractionType::Pet
+        }
+    }
+
     pub fn interact_with_animal(
         animal: &mut Animal,
         inventory: &mut Inventory,
+        item_registry: &ItemRegistry,
+        species_registry: &SpeciesRegistry,
         interaction_type: &InteractionType,
     ) {
         match interaction_type {
             InteractionType::Pet => {
-                println!("{}很高兴被抚摸!", Self::get_animal_name(animal.animal_type));
+                println!("{}很高兴被抚摸!", species_registry.name(animal.animal_type));
             }
             InteractionType::Feed => {
                 if let Some(item) = inventory.get_selected_item() {
-                    if item.can_use_on_animal(animal.animal_type) {
-                        let favorite_food = animal.get_favorite_food();
+                    let definition = item_registry.get(item.item_type);
+                    if definition.can_use_on_animal(animal.animal_type) {
+                        let favorite_food = species_registry.get(animal.animal_type).get_favorite_food();
                         if item.item_type == favorite_food {
-                            println!("{}最喜欢吃{}了!", Self::get_animal_name(animal.animal_type), item.name);
+                            println!("{}最喜欢吃{}了!", species_registry.name(animal.animal_type), definition.name());
                         } else {
-                            println!("{}吃了{}", Self::get_animal_name(animal.animal_type), item.name);
+                            println!("{}吃了{}", species_registry.name(animal.animal_type), definition.name());
                         }
                     } else {
-                        println!("{}不喜欢吃{}", Self::get_animal_name(animal.animal_type), item.name);
+                        println!("{}不喜欢吃{}", species_registry.name(animal.animal_type), definition.name());
                     }
                 }
             }
             InteractionType::Play => {
-                println!("{}很开心地玩耍!", Self::get_animal_name(animal.animal_type));
+                println!("{}很开心地玩耍!", species_registry.name(animal.animal_type));
             }
         }
     }
 
-    pub fn get_animal_name(animal_type: AnimalType) -> &'static str {
-        match animal_type {
-            AnimalType::Dog => "小狗",
-            AnimalType::Cat => "小猫",
-            AnimalType::Chicken => "小鸡",
-            AnimalType::Cow => "奶牛",
-            AnimalType::Sheep => "小羊",
-            AnimalType::Pig => "小猪",
-            AnimalType::Duck => "小鸭",
-            AnimalType::Horse => "小马",
-        }
-    }
-
     pub fn show_interaction_prompts(
         mut query: Query<(Entity, &Transform, &Interactable, &Animal)>,
         player_query: Query<&Transform, (With<Player>, Without<Animal>)>,
+        inventory: Res<Inventory>,
+        item_registry: Res<ItemRegistry>,
+        species_registry: Res<SpeciesRegistry>,
         mut gizmos: Gizmos,
     ) {
         let player_transform = match player_query.single() {
@@ -202,10 +225,10 @@ impl InteractionSystems {
                 // In a real implementation, you would render text here
                 // For now, we'll just log the interaction
                 if distance <= interactable.interaction_range * 0.8 {
-                    let personality = animal.get_personality();
+                    let personality = species_registry.get(animal.animal_type).get_personality();
                     let prompt = format!("按空格键{}{}", 
-                        interactable.get_interaction_prompt(),
-                        Self::get_animal_name(animal.animal_type));
+                        Self::interaction_for(&inventory, &item_registry, animal.animal_type).label(),
+                        species_registry.name(animal.animal_type));
                     println!("{} - {}", prompt, personality);
                 }
             }
@@ -214,8 +237,11 @@ impl InteractionSystems {
 
     pub fn update_animal_behavior(
         time: Res<Time>,
+        time_of_day: Res<TimeOfDay>,
+        mut skipped_events: EventReader<TimeSkipped>,
         mut query: Query<(&mut AnimalAI, &mut Animal, &Transform)>,
         player_query: Query<&Transform, (With<Player>, Without<Animal>)>,
+        species_registry: Res<SpeciesRegistry>,
     ) {
         let player_transform = match player_query.single() {
             Ok(transform) => transform,
@@ -223,16 +249,17 @@ impl InteractionSystems {
         };
 
         let player_pos = Vec2::new(player_transform.translation.x, player_transform.translation.y);
+        let minutes = time_of_day.elapsed_minutes(&time, &mut skipped_events);
 
         for (mut ai, mut animal, transform) in query.iter_mut() {
             let animal_pos = Vec2::new(transform.translation.x, transform.translation.y);
             let distance_to_player = (player_pos - animal_pos).length();
 
             // Animals with high friendship may follow the player
-            if animal.can_follow_player(animal.friendship_level) && distance_to_player < 100.0 {
+            if species_registry.get(animal.animal_type).can_follow_player(animal.friendship_level) && distance_to_player < 100.0 {
                 if ai.state != AnimalState::Following {
                     ai.state = AnimalState::Following;
-                    println!("{}开始跟随你!", Self::get_animal_name(animal.animal_type));
+                    println!("{}开始跟随你!", species_registry.name(animal.animal_type));
                 }
                 
                 // Move towards player
@@ -242,11 +269,11 @@ impl InteractionSystems {
                 }
             } else if ai.state == AnimalState::Following {
                 ai.state = AnimalState::Idle;
-                println!("{}停止跟随", Self::get_animal_name(animal.animal_type));
+                println!("{}停止跟随", species_registry.name(animal.animal_type));
             }
 
-            // Update hunger over time
-            animal.hunger += time.delta_secs() * 0.5;
+            // Update hunger over game time, half a point per real second at normal speed
+            animal.hunger = (animal.hunger + minutes * 0.5 / BASE_TIME_SCALE).min(100.0);
             if animal.hunger > 80.0 {
                 // Hungry animals seek food
                 if ai.state == AnimalState::Idle {
@@ -5,73 +5,8 @@ use crate::traits::*;
 pub struct InventorySystems;
 
 impl InventorySystems {
-    pub fn setup_inventory(mut commands: Commands) {
-        let initial_items = vec![
-            Item {
-                item_type: ItemType::Apple,
-                quantity: 5,
-                name: "苹果".to_string(),
-                category: ItemCategory::Food,
-                value: 5,
-                description: "新鲜的红苹果".to_string(),
-            },
-            Item {
-                item_type: ItemType::Carrot,
-                quantity: 3,
-                name: "胡萝卜".to_string(),
-                category: ItemCategory::Food,
-                value: 3,
-                description: "脆嫩的胡萝卜".to_string(),
-            },
-            Item {
-                item_type: ItemType::Bone,
-                quantity: 2,
-                name: "骨头".to_string(),
-                category: ItemCategory::Food,
-                value: 8,
-                description: "小狗最爱的骨头".to_string(),
-            },
-            Item {
-                item_type: ItemType::Fish,
-                quantity: 4,
-                name: "鱼".to_string(),
-                category: ItemCategory::Food,
-                value: 6,
-                description: "新鲜的鱼".to_string(),
-            },
-            Item {
-                item_type: ItemType::Milk,
-                quantity: 2,
-                name: "牛奶".to_string(),
-                category: ItemCategory::Food,
-                value: 4,
-                description: "新鲜的牛奶".to_string(),
-            },
-            Item {
-                item_type: ItemType::Flower,
-                quantity: 6,
-                name: "花".to_string(),
-                category: ItemCategory::Decoration,
-                value: 3,
-                description: "美丽的花".to_string(),
-            },
-            Item {
-                item_type: ItemType::Shovel,
-                quantity: 1,
-                name: "铲子".to_string(),
-                category: ItemCategory::Tool,
-                value: 15,
-                description: "园艺铲子".to_string(),
-            },
-            Item {
-                item_type: ItemType::Egg,
-                quantity: 3,
-                name: "鸡蛋".to_string(),
-                category: ItemCategory::Special,
-                value: 4,
-                description: "新鲜的鸡蛋".to_string(),
-            },
-        ];
+    pub fn setup_inventory(mut commands: Commands, item_registry: Res<ItemRegistry>) {
+        let initial_items = item_registry.starting_inventory.clone();
 
         commands.insert_resource(Inventory {
             items: initial_items,
@@ -82,16 +17,17 @@ impl InventorySystems {
     }
 
     pub fn toggle_inventory(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         mut inventory: ResMut<Inventory>,
+        item_registry: Res<ItemRegistry>,
     ) {
-        if keyboard.just_pressed(KeyCode::Tab) {
+        if actions.just_pressed(InputAction::ToggleInventory) {
             inventory.is_open = !inventory.is_open;
             if inventory.is_open {
                 println!("打开物品栏 - 当前物品: {}", inventory.items.len());
                 for (i, item) in inventory.items.iter().enumerate() {
                     let selected = if i == inventory.selected_index { " [选中]" } else { "" };
-                    println!("  {}. {}x{}{}", i + 1, item.name, item.quantity, selected);
+                    println!("  {}. {}x{}{}", i + 1, item_registry.name(item.item_type), item.quantity, selected);
                 }
             } else {
                 println!("关闭物品栏");
@@ -99,57 +35,52 @@ impl InventorySystems {
         }
     }
 
+    // The arrow keys only step through the inventory while it is open, the bumpers any time
     pub fn handle_inventory_input(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         mut inventory: ResMut<Inventory>,
+        item_registry: Res<ItemRegistry>,
     ) {
-        if !inventory.is_open {
-            return;
-        }
-
         let mut changed = false;
         
-        if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        if actions.just_pressed(InputAction::SelectPrevious) {
             if inventory.selected_index > 0 {
                 inventory.selected_index -= 1;
                 changed = true;
             }
-        } else if keyboard.just_pressed(KeyCode::ArrowRight) {
+        } else if actions.just_pressed(InputAction::SelectNext) {
             if inventory.selected_index < inventory.items.len().saturating_sub(1) {
                 inventory.selected_index += 1;
                 changed = true;
             }
-        } else if keyboard.just_pressed(KeyCode::Digit1) {
-            if inventory.items.len() > 0 {
-                inventory.selected_index = 0;
-                changed = true;
-            }
-        } else if keyboard.just_pressed(KeyCode::Digit2) {
-            if inventory.items.len() > 1 {
-                inventory.selected_index = 1;
-                changed = true;
-            }
-        } else if keyboard.just_pressed(KeyCode::Digit3) {
-            if inventory.items.len() > 2 {
-                inventory.selected_index = 2;
+        } else if let Some(slot) = (1..=HOTBAR_SLOTS).find(|&slot| actions.just_pressed(InputAction::SelectSlot(slot))) {
+            let index = usize::from(slot - 1);
+            if index < inventory.items.len() {
+                inventory.selected_index = index;
                 changed = true;
             }
         }
 
         if changed {
             if let Some(item) = inventory.get_selected_item() {
-                println!("选中: {}x{}", item.name, item.quantity);
+                println!("选中: {}x{}", item_registry.name(item.item_type), item.quantity);
             }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn use_selected_item(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         mut inventory: ResMut<Inventory>,
+        item_registry: Res<ItemRegistry>,
+        species_registry: Res<SpeciesRegistry>,
         mut animal_query: Query<(Entity, &Transform, &mut Animal, &mut Interactable)>,
         player_query: Query<&Transform, (With<Player>, Without<Animal>)>,
+        mut fed_events: EventWriter<AnimalFed>,
+        mut used_events: EventWriter<ItemUsed>,
+        mut friendship_events: EventWriter<FriendshipChanged>,
     ) {
-        if !keyboard.just_pressed(KeyCode::KeyE) {
+        if !actions.just_pressed(InputAction::UseItem) {
             return;
         }
 
@@ -163,21 +94,23 @@ impl InventorySystems {
         let selected_item = inventory.get_selected_item().cloned();
         
         if let Some(item) = selected_item {
-            if item.quantity <= 0 {
+            if item.quantity == 0 {
                 println!("物品数量不足");
                 return;
             }
 
+            let definition = item_registry.get(item.item_type);
+
             // Find nearby animals
             for (entity, transform, mut animal, mut interactable) in animal_query.iter_mut() {
                 let animal_pos = Vec2::new(transform.translation.x, transform.translation.y);
                 let distance = (player_pos - animal_pos).length();
 
                 if distance <= interactable.interaction_range {
-                    if item.can_use_on_animal(animal.animal_type) {
+                    if definition.can_use_on_animal(animal.animal_type) {
                         // Use the item
-                        let friendship_bonus = item.get_friendship_bonus();
-                        let hunger_reduction = item.get_hunger_reduction();
+                        let friendship_bonus = definition.get_friendship_bonus();
+                        let hunger_reduction = definition.get_hunger_reduction();
                         
                         animal.friendship_level += friendship_bonus;
                         animal.hunger -= hunger_reduction;
@@ -185,26 +118,29 @@ impl InventorySystems {
 
                         // Remove item from inventory
                         inventory.remove_item(&item.item_type, 1);
+                        fed_events.write(AnimalFed { entity, animal_type: animal.animal_type, item_type: item.item_type });
+                        used_events.write(ItemUsed { item_type: item.item_type });
+                        friendship_events.write(FriendshipChanged { animal_type: animal.animal_type, amount: friendship_bonus });
 
-                        let favorite_food = animal.get_favorite_food();
+                        let favorite_food = species_registry.get(animal.animal_type).get_favorite_food();
                         if item.item_type == favorite_food {
                             println!("{}最喜欢吃{}! 好感度+{}, 饥饿度-{}", 
-                                Self::get_animal_name(animal.animal_type),
-                                item.name,
+                                species_registry.name(animal.animal_type),
+                                definition.name(),
                                 friendship_bonus,
                                 hunger_reduction);
                         } else {
                             println!("{}吃了{}! 好感度+{}, 饥饿度-{}", 
-                                Self::get_animal_name(animal.animal_type),
-                                item.name,
+                                species_registry.name(animal.animal_type),
+                                definition.name(),
                                 friendship_bonus,
                                 hunger_reduction);
                         }
                         break;
                     } else {
                         println!("{}不喜欢吃{}", 
-                            Self::get_animal_name(animal.animal_type),
-                            item.name);
+                            species_registry.name(animal.animal_type),
+                            definition.name());
                     }
                 }
             }
@@ -220,26 +156,16 @@ impl InventorySystems {
         // In a real game, items would be acquired through gameplay
     }
 
-    pub fn show_inventory_status(inventory: Res<Inventory>) {
+    pub fn show_inventory_status(inventory: Res<Inventory>, item_registry: Res<ItemRegistry>) {
         // Show inventory status periodically
         // This could be replaced with a proper UI system
         if inventory.is_open {
             if let Some(item) = inventory.get_selected_item() {
-                println!("当前选中: {}x{} (按E使用)", item.name, item.quantity);
+                let definition = item_registry.get(item.item_type);
+                println!("当前选中: {}x{} (价值 {}) - {} (使用物品键使用)",
+                         definition.name(), item.quantity, definition.value,
+                         definition.description.get(DEFAULT_LOCALE));
             }
         }
     }
-
-    pub fn get_animal_name(animal_type: AnimalType) -> &'static str {
-        match animal_type {
-            AnimalType::Dog => "小狗",
-            AnimalType::Cat => "小猫",
-            AnimalType::Chicken => "小鸡",
-            AnimalType::Cow => "奶牛",
-            AnimalType::Sheep => "小羊",
-            AnimalType::Pig => "小猪",
-            AnimalType::Duck => "小鸭",
-            AnimalType::Horse => "小马",
-        }
-    }
 }
\ No newline at end of file
@@ -0,0 +1,314 @@
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PresentMode, PrimaryWindow, WindowMode};
+use crate::components::*;
+use crate::systems::save_systems::{SaveGameEvent, SaveSystems};
+use crate::systems::ui_systems::PromptLabel;
+
+pub struct MenuSystems;
+
+impl MenuSystems {
+    // Menus are rebuilt from scratch whenever the state, the page or the language changes
+    pub fn rebuild_menu(
+        mut commands: Commands,
+        state: Res<State<GameState>>,
+        localization: Res<Localization>,
+        mut page: ResMut<MenuPage>,
+        mut selection: ResMut<MenuSelection>,
+        root_query: Query<Entity, With<MenuRoot>>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        for entity in root_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        selection.0 = 0;
+
+        let state = *state.get();
+        if state != GameState::Title && state != GameState::Paused {
+            // Leaving a menu from its settings page should reopen it on the main page
+            if *page != MenuPage::Main {
+                *page = MenuPage::Main;
+            }
+            return;
+        }
+
+        let label = |key: &str| localization.get(key).to_string();
+        let (title, entries) = match *page {
+            MenuPage::Main if state == GameState::Title => (label("menu.game_title"), vec![
+                (MenuAction::NewGame, label("menu.new_game"), true),
+                (MenuAction::Continue, label("menu.continue"), SaveSystems::save_exists()),
+                (MenuAction::Settings, label("menu.settings"), true),
+                (MenuAction::Quit, label("menu.quit"), true),
+            ]),
+            MenuPage::Main => (label("menu.paused"), vec![
+                (MenuAction::Resume, label("menu.resume"), true),
+                (MenuAction::Save, label("menu.save"), true),
+                (MenuAction::Settings, label("menu.settings"), true),
+                (MenuAction::Quit, label("menu.quit"), true),
+            ]),
+            MenuPage::Settings => {
+                let (fullscreen, vsync) = window_query
+                    .single()
+                    .map(|window| (window.mode != WindowMode::Windowed, window.present_mode != PresentMode::AutoNoVsync))
+                    .unwrap_or((false, true));
+                let mode = localization.get(if fullscreen { "menu.fullscreen" } else { "menu.windowed" });
+                let vsync = localization.get(if vsync { "menu.on" } else { "menu.off" });
+                (label("menu.settings"), vec![
+                    (MenuAction::ToggleFullscreen, localization.format("menu.window_mode", &[("mode", &mode)]), true),
+                    (MenuAction::ToggleVsync, localization.format("menu.vsync", &[("state", &vsync)]), true),
+                    (MenuAction::ToggleLanguage, localization.format("menu.language", &[("language", &localization.language_name())]), true),
+                    (MenuAction::Back, label("menu.back"), true),
+                ])
+            }
+        };
+
+        // The title screen hides the empty world; the pause menu lets the farm show through
+        let backdrop = if state == GameState::Title {
+            Color::srgb(0.2, 0.35, 0.2)
+        } else {
+            Color::srgba(0.0, 0.0, 0.0, 0.6)
+        };
+
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                BackgroundColor(backdrop),
+                GlobalZIndex(20),
+                MenuRoot,
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(title),
+                    TextFont {
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.9, 0.6)),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(20.0)),
+                        ..default()
+                    },
+                ));
+
+                for (index, (action, label, enabled)) in entries.into_iter().enumerate() {
+                    let text_color = if enabled {
+                        Color::srgb(1.0, 1.0, 1.0)
+                    } else {
+                        Color::srgb(0.5, 0.5, 0.5)
+                    };
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(240.0),
+                                height: Val::Px(44.0),
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(MENU_BUTTON_COLOR),
+                            BorderColor(Color::srgb(0.8, 0.7, 0.5)),
+                            MenuButton { action, index, enabled },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new(label),
+                                TextFont {
+                                    font_size: 20.0,
+                                    ..default()
+                                },
+                                TextColor(text_color),
+                            ));
+                        });
+                }
+
+                parent.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    Node {
+                        margin: UiRect::top(Val::Px(20.0)),
+                        ..default()
+                    },
+                    PromptLabel {
+                        key: "menu.hint",
+                        inputs: &[
+                            ("up", InputContext::Menu, InputAction::MenuUp),
+                            ("down", InputContext::Menu, InputAction::MenuDown),
+                            ("confirm", InputContext::Menu, InputAction::Confirm),
+                        ],
+                    },
+                ));
+            });
+    }
+
+    // Keyboard moves the selection, the mouse hovers and clicks
+    #[allow(clippy::too_many_arguments)]
+    pub fn handle_menu_input(
+        mut commands: Commands,
+        actions: Res<ActionState>,
+        mut selection: ResMut<MenuSelection>,
+        mut page: ResMut<MenuPage>,
+        mut localization: ResMut<Localization>,
+        mut next_state: ResMut<NextState<GameState>>,
+        mut save_events: EventWriter<SaveGameEvent>,
+        mut exit_events: EventWriter<AppExit>,
+        mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+        mut button_query: Query<(Ref<Interaction>, &MenuButton, &mut BackgroundColor)>,
+    ) {
+        let count = button_query.iter().count();
+        if count == 0 {
+            return;
+        }
+
+        if actions.just_pressed(InputAction::MenuDown) {
+            selection.0 = (selection.0 + 1) % count;
+        }
+        if actions.just_pressed(InputAction::MenuUp) {
+            selection.0 = (selection.0 + count - 1) % count;
+        }
+
+        let mut activated = None;
+        // Only react to the mouse when it moves onto a button, so a resting cursor doesn't fight the keys
+        for (interaction, button, _) in button_query.iter().filter(|(interaction, _, _)| interaction.is_changed()) {
+            match *interaction {
+                Interaction::Pressed => {
+                    selection.0 = button.index;
+                    activated = Some(*button);
+                }
+                Interaction::Hovered => selection.0 = button.index,
+                Interaction::None => {}
+            }
+        }
+        if actions.just_pressed(InputAction::Confirm) {
+            activated = button_query.iter().map(|(_, button, _)| *button).find(|button| button.index == selection.0);
+        }
+
+        for (_, button, mut color) in button_query.iter_mut() {
+            color.0 = if button.index == selection.0 { MENU_SELECTED_COLOR } else { MENU_BUTTON_COLOR };
+        }
+
+        let Some(button) = activated.filter(|button| button.enabled) else {
+            return;
+        };
+
+        match button.action {
+            MenuAction::NewGame => {
+                println!("开始新游戏");
+                next_state.set(GameState::Loading);
+            }
+            MenuAction::Continue => {
+                if SaveSystems::load_saved_game(&mut commands) {
+                    next_state.set(GameState::Loading);
+                }
+            }
+            MenuAction::Resume => next_state.set(GameState::Playing),
+            MenuAction::Save => {
+                save_events.write(SaveGameEvent);
+            }
+            MenuAction::Settings => *page = MenuPage::Settings,
+            MenuAction::Back => *page = MenuPage::Main,
+            MenuAction::ToggleFullscreen => {
+                if let Ok(mut window) = window_query.single_mut() {
+                    window.mode = if window.mode == WindowMode::Windowed {
+                        WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+                    } else {
+                        WindowMode::Windowed
+                    };
+                }
+                // Relabel the entries
+                page.set_changed();
+            }
+            MenuAction::ToggleVsync => {
+                if let Ok(mut window) = window_query.single_mut() {
+                    window.present_mode = if window.present_mode == PresentMode::AutoNoVsync {
+                        PresentMode::AutoVsync
+                    } else {
+                        PresentMode::AutoNoVsync
+                    };
+                }
+                page.set_changed();
+            }
+            // Rebuilding on the language change relabels the entries
+            MenuAction::ToggleLanguage => localization.next_locale(),
+            MenuAction::Quit => {
+                exit_events.write(AppExit::Success);
+            }
+        }
+    }
+
+    // Escape pauses the game, and backs out of the pause menu again
+    pub fn toggle_pause(
+        actions: Res<ActionState>,
+        state: Res<State<GameState>>,
+        mut next_state: ResMut<NextState<GameState>>,
+        mut page: ResMut<MenuPage>,
+    ) {
+        if !actions.just_pressed(InputAction::Pause) {
+            return;
+        }
+
+        match state.get() {
+            GameState::Playing => next_state.set(GameState::Paused),
+            GameState::Paused if *page == MenuPage::Settings => *page = MenuPage::Main,
+            GameState::Paused => next_state.set(GameState::Playing),
+            _ => {}
+        }
+    }
+
+    // Runs after the save, if any, has been restored onto the freshly spawned farm
+    pub fn finish_loading(mut next_state: ResMut<NextState<GameState>>) {
+        next_state.set(GameState::Playing);
+    }
+}
+
+const MENU_BUTTON_COLOR: Color = Color::srgba(0.1, 0.08, 0.05, 0.9);
+const MENU_SELECTED_COLOR: Color = Color::srgba(0.45, 0.35, 0.15, 0.95);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuAction {
+    NewGame,
+    Continue,
+    Resume,
+    Save,
+    Settings,
+    ToggleFullscreen,
+    ToggleVsync,
+    ToggleLanguage,
+    Back,
+    Quit,
+}
+
+#[derive(Component)]
+pub struct MenuRoot;
+
+#[derive(Component, Clone, Copy)]
+pub struct MenuButton {
+    pub action: MenuAction,
+    pub index: usize,
+    pub enabled: bool,
+}
+
+// Which page of the title or pause menu is showing
+#[derive(Resource, Default, PartialEq, Eq)]
+pub enum MenuPage {
+    #[default]
+    Main,
+    Settings,
+}
+
+// Index of the highlighted button on the current page
+#[derive(Resource, Default)]
+pub struct MenuSelection(pub usize);
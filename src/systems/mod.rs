@@ -10,6 +10,11 @@ pub mod animal_production_systems;
 pub mod visual_feedback_systems;
 pub mod ui_systems;
 pub mod animal_animation_systems;
+pub mod save_systems;
+pub mod weather_effects_systems;
+pub mod day_summary_systems;
+pub mod menu_systems;
+pub mod input_systems;
 
 pub use animal_systems::*;
 pub use interaction_systems::*;
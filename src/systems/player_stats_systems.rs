@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::save_systems::SaveGameEvent;
 
 pub struct PlayerSystems;
 
@@ -11,13 +12,14 @@ impl PlayerSystems {
     pub fn update_player_stats(
         time: Res<Time>,
         mut player_stats: ResMut<PlayerStats>,
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
+        weather: Res<Weather>,
     ) {
         // Update hunger over time (decreases slowly)
         player_stats.update_hunger(-0.5 * time.delta_secs());
         
         // Update fatigue based on movement
-        if keyboard.any_pressed([KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD]) {
+        if actions.movement() != Vec2::ZERO {
             player_stats.update_fatigue(2.0 * time.delta_secs());
             player_stats.update_energy(-1.0 * time.delta_secs());
         } else {
@@ -26,6 +28,10 @@ impl PlayerSystems {
             player_stats.update_energy(0.5 * time.delta_secs());
         }
         
+        // Cold weather drains energy, more the colder it gets
+        let chill = ((COLD_TEMPERATURE - weather.temperature) / 10.0).clamp(0.0, 1.0);
+        player_stats.update_energy(-chill * time.delta_secs());
+        
         // Update fatigue based on time of day
         // This will be connected to the time system later
         
@@ -36,39 +42,40 @@ impl PlayerSystems {
     }
     
     pub fn handle_player_eating(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         mut player_stats: ResMut<PlayerStats>,
         mut inventory: ResMut<Inventory>,
+        item_registry: Res<ItemRegistry>,
+        mut used_events: EventWriter<ItemUsed>,
     ) {
-        if keyboard.just_pressed(KeyCode::KeyE) {
+        if actions.just_pressed(InputAction::Eat) {
             let selected_item = inventory.get_selected_item().cloned();
             
             if let Some(item) = selected_item {
-                if item.category == ItemCategory::Food {
-                    let hunger_restored = match item.item_type {
-                        ItemType::Apple => 15.0,
-                        ItemType::Carrot => 10.0,
-                        ItemType::ApplePie => 25.0,
-                        ItemType::MilkBottle => 12.0,
-                        _ => 5.0,
-                    };
+                let definition = item_registry.get(item.item_type);
+                if definition.category == ItemCategory::Food {
+                    let hunger_restored = definition.player_hunger_restore;
                     
                     player_stats.update_hunger(hunger_restored);
                     player_stats.update_energy(10.0);
                     
                     inventory.remove_item(&item.item_type, 1);
-                    println!("食用了 {} - 饥饿度+{}", item.name, hunger_restored);
+                    used_events.write(ItemUsed { item_type: item.item_type });
+                    println!("食用了 {} - 饥饿度+{}", definition.name(), hunger_restored);
                 }
             }
         }
     }
     
     pub fn handle_player_sleeping(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         mut player_stats: ResMut<PlayerStats>,
         mut time_of_day: ResMut<TimeOfDay>,
+        mut save_events: EventWriter<SaveGameEvent>,
+        mut skipped_events: EventWriter<TimeSkipped>,
+        mut slept_events: EventWriter<PlayerSlept>,
     ) {
-        if keyboard.just_pressed(KeyCode::KeyB) {
+        if actions.just_pressed(InputAction::Sleep) {
             // Sleep only at night
             if time_of_day.current_phase == DayPhase::Night {
                 println!("玩家睡觉了...");
@@ -78,22 +85,36 @@ impl PlayerSystems {
                 player_stats.fatigue = 0.0;
                 player_stats.energy = 100.0;
                 
-                // Advance to morning
+                // Sleeping before midnight skips to the next morning; after midnight the
+                // date has already moved on
+                let now = (time_of_day.hour * 60 + time_of_day.minute) as f32 + time_of_day.minute_progress;
+                let mut wake_up = 6.0 * 60.0;
+                if time_of_day.hour >= 12 {
+                    time_of_day.advance_day();
+                    wake_up += 24.0 * 60.0;
+                }
+                
+                // Everything driven by game time catches up on the night in one step
+                skipped_events.write(TimeSkipped { minutes: wake_up - now });
+                time_of_day.minute_progress = 0.0;
+                
+                // Advance to morning; the new date rolls the quest board over
                 time_of_day.hour = 6;
                 time_of_day.minute = 0;
                 time_of_day.current_phase = DayPhase::Morning;
-                
-                player_stats.day_survived += 1;
-                println!("新的一天开始了！第 {} 天", player_stats.day_survived);
+
+                // Autosave every morning
+                save_events.write(SaveGameEvent);
+                slept_events.write(PlayerSlept);
             } else {
                 println!("只能晚上睡觉！");
             }
         }
     }
     
-    pub fn display_player_stats(player_stats: Res<PlayerStats>) {
+    pub fn display_player_stats(player_stats: Res<PlayerStats>, time_of_day: Res<TimeOfDay>) {
         // Display stats every 30 seconds
-        if player_stats.day_survived % 30 == 0 {
+        if time_of_day.day % 30 == 0 {
             println!("玩家状态 - 饥饿度: {:.1}, 疲劳度: {:.1}, 精力: {:.1}, 金币: {}", 
                 player_stats.hunger, player_stats.fatigue, player_stats.energy, player_stats.coins);
         }
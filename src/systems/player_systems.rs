@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::f32::consts::PI;
 use crate::components::*;
 
 pub struct PlayerSystems;
@@ -7,355 +8,315 @@ impl PlayerSystems {
     pub fn spawn_player(mut commands: Commands) {
         let x = 0.0;
         let y = 0.0;
-        
-        // Player shadow
-        commands.spawn((
-            Sprite {
-                color: Color::srgba(0.1, 0.1, 0.1, 0.3),
-                custom_size: Some(Vec2::new(20.0, 8.0)),
-                ..default()
-            },
-            Transform::from_xyz(x, y - 12.0, -0.5),
-            PlayerPart,
-        ));
-        
-        // Player legs (for walking animation)
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.2, 0.2, 0.6),
-                custom_size: Some(Vec2::new(6.0, 12.0)),
-                ..default()
-            },
-            Transform::from_xyz(x - 3.0, y - 8.0, 0.9),
-            PlayerPart,
-        ));
-        
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.2, 0.2, 0.6),
-                custom_size: Some(Vec2::new(6.0, 12.0)),
-                ..default()
-            },
-            Transform::from_xyz(x + 3.0, y - 8.0, 0.9),
-            PlayerPart,
-        ));
-        
-        // Player feet
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.1, 0.1, 0.3),
-                custom_size: Some(Vec2::new(8.0, 4.0)),
-                ..default()
-            },
-            Transform::from_xyz(x - 3.0, y - 14.0, 0.8),
-            PlayerPart,
-        ));
-        
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.1, 0.1, 0.3),
-                custom_size: Some(Vec2::new(8.0, 4.0)),
-                ..default()
-            },
-            Transform::from_xyz(x + 3.0, y - 14.0, 0.8),
-            PlayerPart,
-        ));
-        
-        // Player body (main color)
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.4, 0.4, 0.9),
-                custom_size: Some(Vec2::new(16.0, 20.0)),
-                ..default()
-            },
-            Transform::from_xyz(x, y, 1.0),
-            PlayerPart,
-        ));
-        
-        // Player arms
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.3, 0.3, 0.8),
-                custom_size: Some(Vec2::new(5.0, 14.0)),
-                ..default()
-            },
-            Transform::from_xyz(x - 8.0, y - 2.0, 0.95),
-            PlayerPart,
-        ));
-        
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.3, 0.3, 0.8),
-                custom_size: Some(Vec2::new(5.0, 14.0)),
-                ..default()
-            },
-            Transform::from_xyz(x + 8.0, y - 2.0, 0.95),
-            PlayerPart,
-        ));
-        
-        // Player hands
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.9, 0.8, 0.7),
-                custom_size: Some(Vec2::new(6.0, 6.0)),
-                ..default()
-            },
-            Transform::from_xyz(x - 8.0, y + 6.0, 1.05),
-            PlayerPart,
-        ));
-        
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.9, 0.8, 0.7),
-                custom_size: Some(Vec2::new(6.0, 6.0)),
-                ..default()
-            },
-            Transform::from_xyz(x + 8.0, y + 6.0, 1.05),
-            PlayerPart,
-        ));
-        
-        // Player head
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.9, 0.8, 0.7),
-                custom_size: Some(Vec2::new(16.0, 16.0)),
-                ..default()
-            },
-            Transform::from_xyz(x, y + 14.0, 1.1),
-            PlayerPart,
-        ));
-        
-        // Player hair
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.3, 0.2, 0.1),
-                custom_size: Some(Vec2::new(14.0, 10.0)),
-                ..default()
-            },
-            Transform::from_xyz(x, y + 20.0, 1.2),
-            PlayerPart,
-        ));
-        
-        // Player eyes
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.1, 0.1, 0.1),
-                custom_size: Some(Vec2::new(2.0, 2.0)),
-                ..default()
-            },
-            Transform::from_xyz(x - 3.0, y + 15.0, 1.3),
-            PlayerPart,
-        ));
-        
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.1, 0.1, 0.1),
-                custom_size: Some(Vec2::new(2.0, 2.0)),
-                ..default()
-            },
-            Transform::from_xyz(x + 3.0, y + 15.0, 1.3),
-            PlayerPart,
-        ));
-        
-        // Player mouth
-        commands.spawn((
-            Sprite {
-                color: Color::srgb(0.3, 0.2, 0.2),
-                custom_size: Some(Vec2::new(4.0, 2.0)),
-                ..default()
-            },
-            Transform::from_xyz(x, y + 11.0, 1.3),
-            PlayerPart,
-        ));
-        
-        // Player main component (for movement logic)
-        commands.spawn((
-            Sprite {
-                color: Color::srgba(0.0, 0.0, 0.0, 0.0), // Invisible
-                custom_size: Some(Vec2::new(1.0, 1.0)),
-                ..default()
-            },
-            Transform::from_xyz(x, y, 1.0),
-            Player {
-                speed: 100.0,
-                run_speed: 200.0,
-            },
-            PlayerDirection {
-                direction: Vec2::ZERO,
-            },
-            PlayerAnimation {
-                frame_timer: Timer::from_seconds(0.2, TimerMode::Repeating),
-                current_frame: 0,
-                is_walking: false,
-                facing_left: false,
-            },
-        ));
+
+        // The parts are children, so they follow the player and are posed relative to it
+        commands
+            .spawn((
+                Transform::from_xyz(x, y, 1.0),
+                Visibility::default(),
+                Player {
+                    speed: 100.0,
+                    run_speed: 200.0,
+                },
+                // Around the feet, so the head can overlap things drawn behind the player
+                Collider::new(Vec2::new(14.0, 8.0)).with_offset(Vec2::new(0.0, -12.0)),
+                PlayerDirection {
+                    direction: Vec2::ZERO,
+                },
+                PlayerAnimation::new(),
+            ))
+            .with_children(|parent| {
+                for (part, color, size) in PLAYER_PARTS {
+                    let pose = PartPose::base(part, Facing::Down);
+                    parent.spawn((
+                        Sprite {
+                            color,
+                            custom_size: Some(size),
+                            ..default()
+                        },
+                        Transform::from_translation(pose.offset).with_scale(pose.scale.extend(1.0)),
+                        if pose.visible { Visibility::Inherited } else { Visibility::Hidden },
+                        part,
+                    ));
+                }
+            });
     }
 
     pub fn player_movement(
-        keyboard: Res<ButtonInput<KeyCode>>,
+        actions: Res<ActionState>,
         time: Res<Time>,
-        mut player_query: Query<(&Player, &mut Transform, &mut PlayerDirection, &mut PlayerAnimation), Without<PlayerPart>>,
-        mut parts_query: Query<&mut Transform, With<PlayerPart>>,
+        obstacles: Obstacles,
+        bounds: Res<WorldBounds>,
+        mut player_query: Query<(&Player, &Collider, &mut Transform, &mut PlayerDirection, &mut PlayerAnimation)>,
     ) {
-        for (player, mut transform, mut direction, mut animation) in player_query.iter_mut() {
-            let mut movement = Vec2::ZERO;
-            
-            if keyboard.pressed(KeyCode::KeyW) || keyboard.pressed(KeyCode::ArrowUp) {
-                movement.y += 1.0;
-            }
-            if keyboard.pressed(KeyCode::KeyS) || keyboard.pressed(KeyCode::ArrowDown) {
-                movement.y -= 1.0;
-            }
-            if keyboard.pressed(KeyCode::KeyA) || keyboard.pressed(KeyCode::ArrowLeft) {
-                movement.x -= 1.0;
-            }
-            if keyboard.pressed(KeyCode::KeyD) || keyboard.pressed(KeyCode::ArrowRight) {
-                movement.x += 1.0;
+        for (player, collider, mut transform, mut direction, mut animation) in player_query.iter_mut() {
+            let movement = actions.movement();
+            let running = actions.pressed(InputAction::Run);
+
+            // Update facing and the clip; a tool swing finishes before walking takes over again
+            if let Some(facing) = Facing::from_movement(movement) {
+                animation.facing = facing;
             }
-            
-            // Update walking state and facing direction
-            animation.is_walking = movement != Vec2::ZERO;
-            if movement.x < 0.0 {
-                animation.facing_left = true;
-            } else if movement.x > 0.0 {
-                animation.facing_left = false;
+            if animation.tool_timer.finished() {
+                animation.set_clip(match (movement != Vec2::ZERO, running) {
+                    (false, _) => PlayerClip::Idle,
+                    (true, false) => PlayerClip::Walk,
+                    (true, true) => PlayerClip::Run,
+                });
             }
-            
+
+            // A half-pushed stick walks at half speed
             if movement != Vec2::ZERO {
-                movement = movement.normalize();
-                
-                let current_speed = if keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight) {
+                let current_speed = if running {
                     player.run_speed
                 } else {
                     player.speed
                 };
-                
+
                 let movement_vector = movement * current_speed * time.delta_secs();
-                let old_pos = transform.translation;
-                transform.translation.x += movement_vector.x;
-                transform.translation.y += movement_vector.y;
-                
-                // Move all player parts
-                let delta = transform.translation - old_pos;
-                for mut part_transform in parts_query.iter_mut() {
-                    part_transform.translation.x += delta.x;
-                    part_transform.translation.y += delta.y;
-                }
-                
-                direction.direction = movement;
+                let resolved = obstacles.resolve(transform.translation.truncate(), movement_vector, collider, false);
+                let resolved = bounds.clamp_body(resolved, collider);
+                transform.translation.x = resolved.x;
+                transform.translation.y = resolved.y;
+
+                direction.direction = movement.normalize();
             } else {
                 direction.direction = Vec2::ZERO;
             }
         }
     }
 
+    // Collecting, feeding, petting and using items all swing the tool
+    pub fn trigger_tool_animation(
+        mut collected_events: EventReader<ProductCollected>,
+        mut fed_events: EventReader<AnimalFed>,
+        mut petted_events: EventReader<AnimalPetted>,
+        mut played_events: EventReader<AnimalPlayedWith>,
+        mut used_events: EventReader<ItemUsed>,
+        mut player_query: Query<&mut PlayerAnimation, With<Player>>,
+    ) {
+        let actions = collected_events.read().count()
+            + fed_events.read().count()
+            + petted_events.read().count()
+            + played_events.read().count()
+            + used_events.read().count();
+        if actions == 0 {
+            return;
+        }
+
+        for mut animation in player_query.iter_mut() {
+            animation.use_tool();
+        }
+    }
+
+    // Poses every part from scratch each frame: the facing picks the base pose, the clip moves it
     pub fn animate_player(
         time: Res<Time>,
-        mut player_query: Query<(&mut PlayerAnimation, &Transform), Without<PlayerPart>>,
-        mut parts_query: Query<&mut Transform, With<PlayerPart>>,
+        mut player_query: Query<(&mut PlayerAnimation, &Children), With<Player>>,
+        mut parts_query: Query<(&PlayerPart, &mut Transform, &mut Visibility)>,
     ) {
-        for (mut animation, player_transform) in player_query.iter_mut() {
-            // Get the player parts for animation
-            let mut legs = Vec::new();
-            let mut feet = Vec::new();
-            let mut arms = Vec::new();
-            
-            for part_transform in parts_query.iter_mut() {
-                let rel_x = part_transform.translation.x - player_transform.translation.x;
-                let rel_y = part_transform.translation.y - player_transform.translation.y;
-                
-                // Identify which part this is based on relative position
-                if (rel_y - (-8.0)).abs() < 1.0 { // Legs at y = -8
-                    if (rel_x - (-3.0)).abs() < 1.0 { // Left leg
-                        legs.push((part_transform, -3.0));
-                    } else if (rel_x - 3.0).abs() < 1.0 { // Right leg
-                        legs.push((part_transform, 3.0));
+        for (mut animation, children) in player_query.iter_mut() {
+            animation.clip_time += time.delta_secs();
+            animation.tool_timer.tick(time.delta());
+
+            let t = animation.clip_time;
+            // -1 facing left, 1 facing right, 0 facing up or down
+            let side = match animation.facing {
+                Facing::Left => -1.0,
+                Facing::Right => 1.0,
+                Facing::Up | Facing::Down => 0.0,
+            };
+
+            for &child in children {
+                let Ok((part, mut transform, mut visibility)) = parts_query.get_mut(child) else {
+                    continue;
+                };
+                let mut pose = PartPose::base(*part, animation.facing);
+
+                match animation.clip {
+                    PlayerClip::Idle => {
+                        // Slow breathing: the chest swells and the head rises with it
+                        let breath = (t * BREATH_SPEED).sin();
+                        match part {
+                            PlayerPart::Body => pose.scale.y *= 1.0 + 0.03 * breath,
+                            PlayerPart::Head | PlayerPart::Hair | PlayerPart::LeftEye | PlayerPart::RightEye | PlayerPart::Mouth => {
+                                pose.offset.y += 0.4 * breath;
+                            }
+                            PlayerPart::LeftArm | PlayerPart::RightArm | PlayerPart::LeftHand | PlayerPart::RightHand => {
+                                pose.offset.y += 0.2 * breath;
+                            }
+                            _ => {}
+                        }
                     }
-                } else if (rel_y - (-14.0)).abs() < 1.0 { // Feet at y = -14
-                    if (rel_x - (-3.0)).abs() < 1.0 { // Left foot
-                        feet.push((part_transform, -3.0));
-                    } else if (rel_x - 3.0).abs() < 1.0 { // Right foot
-                        feet.push((part_transform, 3.0));
+                    PlayerClip::Walk | PlayerClip::Run => {
+                        let (speed, stride) = if animation.clip == PlayerClip::Run { (14.0, 3.5) } else { (9.0, 2.0) };
+                        let swing = (t * speed).sin();
+                        // Legs swing against each other, and each arm against its own leg
+                        let phase = match part {
+                            PlayerPart::LeftLeg | PlayerPart::LeftFoot | PlayerPart::RightArm | PlayerPart::RightHand => 1.0,
+                            PlayerPart::RightLeg | PlayerPart::RightFoot | PlayerPart::LeftArm | PlayerPart::LeftHand => -1.0,
+                            _ => 0.0,
+                        };
+                        if side == 0.0 {
+                            // Seen from the front or back, steps show as the feet lifting
+                            pose.offset.y += phase * swing * stride * 0.5;
+                        } else {
+                            pose.offset.x += phase * swing * stride * side;
+                        }
+
+                        // The upper body bobs once per step
+                        if matches!(part, PlayerPart::Body | PlayerPart::Head | PlayerPart::Hair | PlayerPart::LeftEye | PlayerPart::RightEye | PlayerPart::Mouth) {
+                            pose.offset.y += swing.abs() * stride * 0.25;
+                        }
                     }
-                } else if (rel_y - (-2.0)).abs() < 1.0 { // Arms at y = -2
-                    if (rel_x - (-8.0)).abs() < 1.0 { // Left arm
-                        arms.push((part_transform, -8.0));
-                    } else if (rel_x - 8.0).abs() < 1.0 { // Right arm
-                        arms.push((part_transform, 8.0));
+                    PlayerClip::UseTool => {
+                        // The arm on the facing side (the right one from the front or back)
+                        // swings out and back holding the tool
+                        let tool_side = if side < 0.0 { -1.0 } else { 1.0 };
+                        let (arm, hand) = if side < 0.0 {
+                            (PlayerPart::LeftArm, PlayerPart::LeftHand)
+                        } else {
+                            (PlayerPart::RightArm, PlayerPart::RightHand)
+                        };
+                        let angle = (animation.tool_timer.fraction() * PI).sin() * TOOL_SWING_ANGLE * tool_side;
+                        let shoulder = PartPose::base(arm, animation.facing).offset + Vec3::new(0.0, 5.0, 0.0);
+                        let reach = |length: f32| shoulder.truncate() + Vec2::from_angle(angle).rotate(Vec2::new(0.0, -length));
+
+                        if *part == arm {
+                            pose.offset = reach(7.0).extend(pose.offset.z);
+                            pose.rotation = angle;
+                        } else if *part == hand {
+                            pose.offset = reach(12.0).extend(pose.offset.z);
+                        } else if *part == PlayerPart::Tool {
+                            pose.offset = reach(18.0).extend(shoulder.z + 0.01);
+                            pose.rotation = angle;
+                            pose.visible = true;
+                        }
                     }
                 }
+
+                transform.translation = pose.offset;
+                transform.scale = pose.scale.extend(1.0);
+                transform.rotation = Quat::from_rotation_z(pose.rotation);
+                visibility.set_if_neq(if pose.visible { Visibility::Inherited } else { Visibility::Hidden });
             }
-            
-            // Animate based on walking state
-            if animation.is_walking {
-                // Update animation frame
-                animation.frame_timer.tick(time.delta());
-                if animation.frame_timer.just_finished() {
-                    animation.current_frame = (animation.current_frame + 1) % 4;
-                }
-                
-                // Walking animation offsets
-                let frame = animation.current_frame;
-                let left_leg_offset = match frame {
-                    0 => 0.0,
-                    1 => 2.0,
-                    2 => 0.0,
-                    _ => -2.0,
-                };
-                let right_leg_offset = match frame {
-                    0 => 0.0,
-                    1 => -2.0,
-                    2 => 0.0,
-                    _ => 2.0,
-                };
-                
-                // Animate legs
-                for (leg_transform, base_x) in legs.iter_mut() {
-                    let offset = if *base_x < 0.0 { left_leg_offset } else { right_leg_offset };
-                    leg_transform.translation.x = player_transform.translation.x + *base_x + offset;
-                }
-                
-                // Animate feet (following legs)
-                for (foot_transform, base_x) in feet.iter_mut() {
-                    let offset = if *base_x < 0.0 { left_leg_offset } else { right_leg_offset };
-                    foot_transform.translation.x = player_transform.translation.x + *base_x + offset;
-                }
-                
-                // Animate arms (swinging opposite to legs)
-                let arm_swing = match frame {
-                    0 => 0.0,
-                    1 => -1.5,
-                    2 => 0.0,
-                    _ => 1.5,
-                };
-                
-                for (arm_transform, base_x) in arms.iter_mut() {
-                    let offset = if (*base_x < 0.0) != animation.facing_left {
-                        arm_swing
-                    } else {
-                        -arm_swing
-                    };
-                    arm_transform.translation.x = player_transform.translation.x + *base_x + offset;
-                }
-                
-            } else {
-                // Reset to idle position
-                animation.current_frame = 0;
-                
-                // Reset all parts to base positions
-                for (leg_transform, base_x) in legs.iter_mut() {
-                    leg_transform.translation.x = player_transform.translation.x + *base_x;
-                }
-                
-                for (foot_transform, base_x) in feet.iter_mut() {
-                    foot_transform.translation.x = player_transform.translation.x + *base_x;
-                }
-                
-                for (arm_transform, base_x) in arms.iter_mut() {
-                    arm_transform.translation.x = player_transform.translation.x + *base_x;
+        }
+    }
+}
+
+// Breaths per second times 2π
+const BREATH_SPEED: f32 = 2.0;
+// How far the tool arm swings out, in radians
+const TOOL_SWING_ANGLE: f32 = 1.4;
+
+// Color and size of each part
+const PLAYER_PARTS: [(PlayerPart, Color, Vec2); 16] = [
+    (PlayerPart::Shadow, Color::srgba(0.1, 0.1, 0.1, 0.3), Vec2::new(20.0, 8.0)),
+    (PlayerPart::LeftLeg, Color::srgb(0.2, 0.2, 0.6), Vec2::new(6.0, 12.0)),
+    (PlayerPart::RightLeg, Color::srgb(0.2, 0.2, 0.6), Vec2::new(6.0, 12.0)),
+    (PlayerPart::LeftFoot, Color::srgb(0.1, 0.1, 0.3), Vec2::new(8.0, 4.0)),
+    (PlayerPart::RightFoot, Color::srgb(0.1, 0.1, 0.3), Vec2::new(8.0, 4.0)),
+    (PlayerPart::Body, Color::srgb(0.4, 0.4, 0.9), Vec2::new(16.0, 20.0)),
+    (PlayerPart::LeftArm, Color::srgb(0.3, 0.3, 0.8), Vec2::new(5.0, 14.0)),
+    (PlayerPart::RightArm, Color::srgb(0.3, 0.3, 0.8), Vec2::new(5.0, 14.0)),
+    (PlayerPart::LeftHand, Color::srgb(0.9, 0.8, 0.7), Vec2::new(6.0, 6.0)),
+    (PlayerPart::RightHand, Color::srgb(0.9, 0.8, 0.7), Vec2::new(6.0, 6.0)),
+    (PlayerPart::Head, Color::srgb(0.9, 0.8, 0.7), Vec2::new(16.0, 16.0)),
+    (PlayerPart::Hair, Color::srgb(0.3, 0.2, 0.1), Vec2::new(14.0, 10.0)),
+    (PlayerPart::LeftEye, Color::srgb(0.1, 0.1, 0.1), Vec2::new(2.0, 2.0)),
+    (PlayerPart::RightEye, Color::srgb(0.1, 0.1, 0.1), Vec2::new(2.0, 2.0)),
+    (PlayerPart::Mouth, Color::srgb(0.3, 0.2, 0.2), Vec2::new(4.0, 2.0)),
+    (PlayerPart::Tool, Color::srgb(0.55, 0.55, 0.6), Vec2::new(3.0, 12.0)),
+];
+
+// Where a part sits relative to the player
+struct PartPose {
+    offset: Vec3,
+    scale: Vec2,
+    rotation: f32,
+    visible: bool,
+}
+
+impl PartPose {
+    fn at(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            offset: Vec3::new(x, y, z),
+            scale: Vec2::ONE,
+            rotation: 0.0,
+            visible: true,
+        }
+    }
+
+    fn scaled(mut self, x: f32, y: f32) -> Self {
+        self.scale = Vec2::new(x, y);
+        self
+    }
+
+    fn hidden(mut self) -> Self {
+        self.visible = false;
+        self
+    }
+
+    // Standing pose for each facing, before any clip moves it
+    fn base(part: PlayerPart, facing: Facing) -> Self {
+        match facing {
+            Facing::Down => match part {
+                PlayerPart::Shadow => Self::at(0.0, -12.0, -1.5),
+                PlayerPart::LeftLeg => Self::at(-3.0, -8.0, -0.1),
+                PlayerPart::RightLeg => Self::at(3.0, -8.0, -0.1),
+                PlayerPart::LeftFoot => Self::at(-3.0, -14.0, -0.2),
+                PlayerPart::RightFoot => Self::at(3.0, -14.0, -0.2),
+                PlayerPart::Body => Self::at(0.0, 0.0, 0.0),
+                PlayerPart::LeftArm => Self::at(-8.0, -2.0, -0.05),
+                PlayerPart::RightArm => Self::at(8.0, -2.0, -0.05),
+                PlayerPart::LeftHand => Self::at(-8.0, 6.0, 0.05),
+                PlayerPart::RightHand => Self::at(8.0, 6.0, 0.05),
+                PlayerPart::Head => Self::at(0.0, 14.0, 0.1),
+                PlayerPart::Hair => Self::at(0.0, 20.0, 0.2),
+                PlayerPart::LeftEye => Self::at(-3.0, 15.0, 0.3),
+                PlayerPart::RightEye => Self::at(3.0, 15.0, 0.3),
+                PlayerPart::Mouth => Self::at(0.0, 11.0, 0.3),
+                PlayerPart::Tool => Self::at(8.0, -8.0, 0.06).hidden(),
+            },
+            // From behind the face is hidden and the hair covers the back of the head
+            Facing::Up => match part {
+                PlayerPart::Hair => Self::at(0.0, 16.0, 0.2).scaled(1.15, 1.6),
+                PlayerPart::LeftEye | PlayerPart::RightEye | PlayerPart::Mouth => Self::base(part, Facing::Down).hidden(),
+                _ => Self::base(part, Facing::Down),
+            },
+            // In profile the near eye, arm and leg are drawn in front and the far ones behind
+            Facing::Left | Facing::Right => {
+                let side = if facing == Facing::Left { -1.0 } else { 1.0 };
+                let near = |left: bool| (side < 0.0) == left;
+                match part {
+                    PlayerPart::Shadow => Self::at(0.0, -12.0, -1.5),
+                    PlayerPart::LeftLeg | PlayerPart::RightLeg => {
+                        let depth = if near(part == PlayerPart::LeftLeg) { -0.1 } else { -0.12 };
+                        Self::at(0.0, -8.0, depth)
+                    }
+                    PlayerPart::LeftFoot | PlayerPart::RightFoot => {
+                        let depth = if near(part == PlayerPart::LeftFoot) { -0.2 } else { -0.22 };
+                        Self::at(side * 1.5, -14.0, depth)
+                    }
+                    PlayerPart::Body => Self::at(0.0, 0.0, 0.0).scaled(0.75, 1.0),
+                    PlayerPart::LeftArm | PlayerPart::RightArm => {
+                        let depth = if near(part == PlayerPart::LeftArm) { 0.06 } else { -0.06 };
+                        Self::at(0.0, -2.0, depth)
+                    }
+                    PlayerPart::LeftHand | PlayerPart::RightHand => {
+                        let depth = if near(part == PlayerPart::LeftHand) { 0.07 } else { -0.07 };
+                        Self::at(0.0, 6.0, depth)
+                    }
+                    PlayerPart::Head => Self::at(0.0, 14.0, 0.1),
+                    PlayerPart::Hair => Self::at(-side * 2.0, 19.0, 0.2).scaled(1.0, 1.2),
+                    PlayerPart::LeftEye | PlayerPart::RightEye => {
+                        let eye = Self::at(side * 4.0, 15.0, 0.3);
+                        if near(part == PlayerPart::LeftEye) { eye } else { eye.hidden() }
+                    }
+                    PlayerPart::Mouth => Self::at(side * 5.0, 11.0, 0.3).scaled(0.5, 1.0),
+                    PlayerPart::Tool => Self::at(0.0, -8.0, 0.08).hidden(),
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
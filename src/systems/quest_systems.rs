@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 use crate::components::*;
 
 pub struct QuestSystems;
@@ -8,239 +9,324 @@ impl QuestSystems {
         commands.insert_resource(QuestManager::new());
     }
     
-    pub fn initialize_daily_quests(
+    // Runs whenever the calendar moves past the quest board's day: on a new game,
+    // after loading an older save, at midnight and right after the player sleeps
+    pub fn roll_over_day(
         mut quest_manager: ResMut<QuestManager>,
+        mut player_stats: ResMut<PlayerStats>,
         time_of_day: Res<TimeOfDay>,
+        quest_catalog: Res<QuestCatalog>,
+        chain_catalog: Res<QuestChainCatalog>,
+        seed: Res<WorldSeed>,
+        mut failed_events: EventWriter<QuestFailed>,
     ) {
-        // Initialize quests at the start of each day
-        if time_of_day.hour == 6 && time_of_day.minute == 0 && quest_manager.daily_quests.is_empty() {
-            Self::generate_daily_quests(&mut quest_manager);
+        let day = time_of_day.day;
+        if quest_manager.current_day == day {
+            return;
+        }
+        
+        let mut expired_stages: Vec<QuestChainLink> = Vec::new();
+        for quest in quest_manager.expire_quests(day) {
+            let mut coins_lost = 0;
+            match &quest.penalty {
+                Some(penalty) => {
+                    coins_lost = penalty.coins.min(player_stats.coins);
+                    player_stats.coins -= coins_lost;
+                    println!("任务失败：{}，扣除 {} 金币", quest.title, coins_lost);
+                }
+                None => println!("任务失败：{}", quest.title),
+            }
+            failed_events.write(QuestFailed { quest: quest.clone(), coins_lost });
+            
+            // All objectives of a stage share its deadline, so handle each stage once
+            if let Some(link) = quest.chain
+                && !expired_stages.iter().any(|stage| stage.chain_id == link.chain_id && stage.stage_id == link.stage_id) {
+                expired_stages.push(link);
+            }
         }
+        for link in expired_stages {
+            Self::expire_chain_stage(&mut quest_manager, &chain_catalog, &link, day);
+        }
+        
+        quest_manager.current_day = day;
+        Self::generate_daily_quests(&mut quest_manager, &quest_catalog, &seed, day);
     }
     
-    pub fn generate_daily_quests(quest_manager: &mut QuestManager) {
-        let daily_quests = vec![
-            Quest {
-                id: "daily_feed_5_animals".to_string(),
-                title: "喂食小动物".to_string(),
-                description: "喂食5只农场动物".to_string(),
-                quest_type: QuestType::FeedAnimals,
-                required_amount: 5,
-                progress: 0,
-                reward: QuestReward {
-                    coins: 20,
-                    items: vec![(ItemType::Apple, 2)],
-                    friendship_bonus: 1,
-                },
-                is_daily: true,
-                deadline: Some(1), // Due by end of day
-            },
-            Quest {
-                id: "daily_collect_3_items".to_string(),
-                title: "收集物品".to_string(),
-                description: "收集3个任意物品".to_string(),
-                quest_type: QuestType::CollectItems,
-                required_amount: 3,
-                progress: 0,
-                reward: QuestReward {
-                    coins: 15,
-                    items: vec![(ItemType::Carrot, 1)],
-                    friendship_bonus: 0,
-                },
-                is_daily: true,
-                deadline: Some(1),
-            },
-            Quest {
-                id: "daily_pet_3_animals".to_string(),
-                title: "抚摸动物".to_string(),
-                description: "抚摸3只动物增加好感度".to_string(),
-                quest_type: QuestType::InteractWithAnimals,
-                required_amount: 3,
-                progress: 0,
-                reward: QuestReward {
-                    coins: 10,
-                    items: vec![(ItemType::Flower, 1)],
-                    friendship_bonus: 2,
-                },
-                is_daily: true,
-                deadline: Some(1),
-            },
-        ];
+    pub fn generate_daily_quests(
+        quest_manager: &mut QuestManager,
+        quest_catalog: &QuestCatalog,
+        seed: &WorldSeed,
+        day: u32,
+    ) {
+        // Same seed and day always draw the same dailies
+        let mut rng = seed.rng(&format!("quests-day-{}", day));
+        let daily_quests: Vec<Quest> = quest_catalog
+            .draw_daily(&mut rng, &quest_manager.completed_quests)
+            .into_iter()
+            .map(|definition| definition.instantiate(day))
+            .collect();
         
         quest_manager.daily_quests = daily_quests.clone();
         for quest in daily_quests {
             quest_manager.add_quest(quest);
         }
         
+        // One-off quests are offered as soon as they unlock
+        let unlocked: Vec<Quest> = quest_catalog
+            .unlocked_one_offs(&quest_manager.active_quests, &quest_manager.completed_quests)
+            .map(|definition| definition.instantiate(day))
+            .collect();
+        for quest in unlocked {
+            println!("新任务解锁：{}", quest.title);
+            quest_manager.add_quest(quest);
+        }
+        
         println!("新的每日任务已生成！");
     }
     
-    pub fn track_feeding_quests(
+    pub fn track_quest_events(
         mut quest_manager: ResMut<QuestManager>,
-        animal_query: Query<&Animal>,
+        mut fed_events: EventReader<AnimalFed>,
+        mut petted_events: EventReader<AnimalPetted>,
+        mut played_events: EventReader<AnimalPlayedWith>,
+        mut collected_events: EventReader<ProductCollected>,
+        mut acquired_events: EventReader<ItemAcquired>,
+        mut used_events: EventReader<ItemUsed>,
     ) {
-        // Count animals with high hunger (recently fed)
-        let recently_fed_count = animal_query.iter()
-            .filter(|animal| animal.hunger > 80.0)
-            .count() as u32;
-        
-        // Update feeding quests
-        let mut quests_to_update = Vec::new();
-        for quest in &quest_manager.active_quests {
-            if quest.quest_type == QuestType::FeedAnimals {
-                quests_to_update.push((quest.id.clone(), recently_fed_count));
-            }
+        for event in fed_events.read() {
+            quest_manager.record_progress(QuestType::FeedAnimals, Some((event.entity, event.animal_type)), Some(event.item_type), 1);
         }
         
-        for (quest_id, progress) in quests_to_update {
-            quest_manager.update_quest_progress(&quest_id, progress);
+        // Petting and playing both count as interacting
+        for event in petted_events.read() {
+            quest_manager.record_progress(QuestType::InteractWithAnimals, Some((event.entity, event.animal_type)), None, 1);
         }
-    }
-    
-    pub fn track_interaction_quests(
-        mut quest_manager: ResMut<QuestManager>,
-        interaction_query: Query<&Interactable>,
-    ) {
-        // Count successful interactions
-        let interaction_count = interaction_query.iter().count() as u32;
-        
-        // Update interaction quests
-        let mut quests_to_update = Vec::new();
-        for quest in &quest_manager.active_quests {
-            if quest.quest_type == QuestType::InteractWithAnimals {
-                quests_to_update.push((quest.id.clone(), interaction_count));
-            }
+        for event in played_events.read() {
+            quest_manager.record_progress(QuestType::InteractWithAnimals, Some((event.entity, event.animal_type)), None, 1);
+        }
+        
+        for event in collected_events.read() {
+            quest_manager.record_progress(QuestType::CollectItems, Some((event.entity, event.animal_type)), Some(event.item_type), 1);
+        }
+        
+        for event in acquired_events.read() {
+            quest_manager.record_progress(QuestType::FindItems, None, Some(event.item_type), event.quantity);
         }
         
-        for (quest_id, progress) in quests_to_update {
-            quest_manager.update_quest_progress(&quest_id, progress);
+        for event in used_events.read() {
+            quest_manager.record_progress(QuestType::UseItems, None, Some(event.item_type), 1);
         }
     }
     
+    #[allow(clippy::too_many_arguments)]
     pub fn check_quest_completion(
         mut quest_manager: ResMut<QuestManager>,
         mut player_stats: ResMut<PlayerStats>,
         mut inventory: ResMut<Inventory>,
+        mut mailbox: ResMut<RewardMailbox>,
+        chain_catalog: Res<QuestChainCatalog>,
+        time_of_day: Res<TimeOfDay>,
+        mut animal_query: Query<(Entity, &mut Animal)>,
+        mut completed_events: EventWriter<QuestCompleted>,
+        mut friendship_events: EventWriter<FriendshipChanged>,
     ) {
-        let mut completed_quests = Vec::new();
+        let completed_quests: Vec<Quest> = quest_manager.active_quests.iter()
+            .filter(|quest| quest.progress >= quest.required_amount)
+            .cloned()
+            .collect();
         
-        for quest in &quest_manager.active_quests {
-            if quest.progress >= quest.required_amount {
-                completed_quests.push(quest.id.clone());
+        for quest in completed_quests {
+            // A sibling branch finishing first in the same frame already closed this stage
+            if !quest_manager.active_quests.iter().any(|active| active.id == quest.id) {
+                continue;
             }
-        }
-        
-        for quest_id in completed_quests {
-            if let Some(quest_index) = quest_manager.active_quests.iter()
-                .position(|q| q.id == quest_id) {
-                let quest = quest_manager.active_quests[quest_index].clone();
-                
-                // Give rewards
-                player_stats.coins += quest.reward.coins;
-                
-                for (item_type, quantity) in quest.reward.items {
-                    let item = Item {
-                        item_type,
-                        quantity,
-                        name: Self::get_item_name(item_type),
-                        category: Self::get_item_category(item_type),
-                        value: Self::get_item_value(item_type),
-                        description: Self::get_item_description(item_type),
-                    };
-                    inventory.add_item(item);
+            
+            let reward = &quest.reward;
+            
+            // Coins
+            player_stats.coins += reward.coins;
+            
+            // Items; whatever doesn't fit waits in the mailbox instead of being lost
+            let mut items_received = Vec::new();
+            let mut items_mailed = Vec::new();
+            for &(item_type, quantity) in &reward.items {
+                if inventory.add_item(Item::new(item_type, quantity)) {
+                    items_received.push((item_type, quantity));
+                } else {
+                    mailbox.deposit(Item::new(item_type, quantity));
+                    items_mailed.push((item_type, quantity));
                 }
-                
-                println!("任务完成：{}！获得 {} 金币", quest.title, quest.reward.coins);
-                quest_manager.complete_quest(&quest_id);
+            }
+            
+            // Friendship goes to the animals that helped, or to every animal if none did
+            let mut animals_rewarded = 0;
+            if reward.friendship_bonus > 0 {
+                let helpers_alive = quest.involved_animals.iter().any(|&entity| animal_query.contains(entity));
+                for (entity, mut animal) in animal_query.iter_mut() {
+                    if !helpers_alive || quest.involved_animals.contains(&entity) {
+                        animal.friendship_level += reward.friendship_bonus;
+                        animals_rewarded += 1;
+                        friendship_events.write(FriendshipChanged { animal_type: animal.animal_type, amount: reward.friendship_bonus });
+                    }
+                }
+            }
+            
+            println!("任务完成：{}！获得 {} 金币", quest.title, reward.coins);
+            if animals_rewarded > 0 {
+                println!("{} 只动物好感度+{}", animals_rewarded, reward.friendship_bonus);
+            }
+            if !items_mailed.is_empty() {
+                println!("物品栏已满，奖励物品已放入邮箱（按M领取）");
+            }
+            
+            completed_events.write(QuestCompleted { quest: quest.clone(), coins: reward.coins });
+            quest_manager.completion_log.push(QuestCompletion {
+                quest_id: quest.id.clone(),
+                title: quest.title.clone(),
+                day: time_of_day.day,
+                coins: reward.coins,
+                items_received,
+                items_mailed,
+                friendship_bonus: reward.friendship_bonus,
+                animals_rewarded,
+            });
+            quest_manager.complete_quest(&quest.id);
+            
+            if let Some(link) = &quest.chain {
+                Self::advance_chain(&mut quest_manager, &chain_catalog, link, time_of_day.day);
             }
         }
     }
     
-    pub fn display_quests(quest_manager: Res<QuestManager>) {
-        // Display active quests periodically
-        if !quest_manager.active_quests.is_empty() {
-            println!("当前活跃任务：");
-            for quest in &quest_manager.active_quests {
-                println!("  - {}: {}/{}", quest.title, quest.progress, quest.required_amount);
+    pub fn unlock_quest_chains(
+        mut quest_manager: ResMut<QuestManager>,
+        chain_catalog: Res<QuestChainCatalog>,
+        time_of_day: Res<TimeOfDay>,
+        animal_query: Query<&Animal>,
+    ) {
+        let mut best_friendship: HashMap<AnimalType, u32> = HashMap::new();
+        for animal in animal_query.iter() {
+            let best = best_friendship.entry(animal.animal_type).or_insert(0);
+            *best = (*best).max(animal.friendship_level);
+        }
+        
+        let day = time_of_day.day;
+        for chain in &chain_catalog.chains {
+            if quest_manager.chain_status(&chain.id).is_some()
+                || !chain.unlock.is_met(day, &best_friendship, &quest_manager) {
+                continue;
+            }
+            
+            let Some(first_stage) = chain.stages.first() else {
+                continue;
+            };
+            
+            println!("新的故事任务：{}", chain.title.get(DEFAULT_LOCALE));
+            quest_manager.chains.push(QuestChainProgress {
+                chain_id: chain.id.clone(),
+                title: chain.title.get(DEFAULT_LOCALE).to_string(),
+                current_stage: Some(first_stage.id.clone()),
+                completed_stages: Vec::new(),
+                status: ChainStatus::Active,
+            });
+            for quest in chain.stage_quests(first_stage, day) {
+                quest_manager.add_quest(quest);
             }
         }
     }
     
-    fn get_item_name(item_type: ItemType) -> String {
-        match item_type {
-            ItemType::Apple => "苹果".to_string(),
-            ItemType::Carrot => "胡萝卜".to_string(),
-            ItemType::Bone => "骨头".to_string(),
-            ItemType::Fish => "鱼".to_string(),
-            ItemType::Milk => "牛奶".to_string(),
-            ItemType::Wheat => "小麦".to_string(),
-            ItemType::Flower => "花朵".to_string(),
-            ItemType::Corn => "玉米".to_string(),
-            ItemType::Hay => "干草".to_string(),
-            ItemType::Toy => "玩具".to_string(),
-            ItemType::Ribbon => "丝带".to_string(),
-            ItemType::Bell => "铃铛".to_string(),
-            ItemType::Egg => "鸡蛋".to_string(),
-            ItemType::Wool => "羊毛".to_string(),
-            ItemType::ApplePie => "苹果派".to_string(),
-            ItemType::MilkBottle => "奶瓶".to_string(),
-            _ => "未知物品".to_string(),
+    // The completed objective decides which stage comes next
+    fn advance_chain(
+        quest_manager: &mut QuestManager,
+        chain_catalog: &QuestChainCatalog,
+        link: &QuestChainLink,
+        day: u32,
+    ) {
+        quest_manager.remove_stage_quests(&link.chain_id, &link.stage_id);
+        if let Some(progress) = quest_manager.chains.iter_mut().find(|chain| chain.chain_id == link.chain_id) {
+            progress.completed_stages.push(link.stage_id.clone());
         }
+        Self::enter_chain_stage(quest_manager, chain_catalog, &link.chain_id, link.next_stage.as_deref(), ChainStatus::Completed, day);
     }
     
-    fn get_item_category(item_type: ItemType) -> ItemCategory {
-        match item_type {
-            ItemType::Apple | ItemType::Carrot | ItemType::Fish | ItemType::Milk | 
-            ItemType::Wheat | ItemType::Corn | ItemType::Hay | ItemType::ApplePie | 
-            ItemType::MilkBottle => ItemCategory::Food,
-            ItemType::WaterBucket | ItemType::Basket | ItemType::Shovel | ItemType::Rake => ItemCategory::Tool,
-            ItemType::Toy | ItemType::Ribbon | ItemType::Bell | ItemType::Flower => ItemCategory::Decoration,
-            ItemType::Egg | ItemType::Wool => ItemCategory::Special,
-            _ => ItemCategory::Food,
-        }
+    fn expire_chain_stage(
+        quest_manager: &mut QuestManager,
+        chain_catalog: &QuestChainCatalog,
+        link: &QuestChainLink,
+        day: u32,
+    ) {
+        quest_manager.remove_stage_quests(&link.chain_id, &link.stage_id);
+        let fallback = chain_catalog
+            .get(&link.chain_id)
+            .and_then(|chain| chain.stage(&link.stage_id))
+            .and_then(|stage| stage.on_expire.clone());
+        Self::enter_chain_stage(quest_manager, chain_catalog, &link.chain_id, fallback.as_deref(), ChainStatus::Failed, day);
     }
     
-    fn get_item_value(item_type: ItemType) -> u32 {
-        match item_type {
-            ItemType::Apple => 5,
-            ItemType::Carrot => 3,
-            ItemType::Bone => 8,
-            ItemType::Fish => 12,
-            ItemType::Milk => 10,
-            ItemType::Wheat => 2,
-            ItemType::Flower => 1,
-            ItemType::Corn => 4,
-            ItemType::Hay => 3,
-            ItemType::Toy => 15,
-            ItemType::Ribbon => 6,
-            ItemType::Bell => 10,
-            ItemType::Egg => 8,
-            ItemType::Wool => 20,
-            ItemType::ApplePie => 25,
-            ItemType::MilkBottle => 12,
-            _ => 1,
+    // Starts the given stage, or ends the chain with `end_status` when there is none
+    fn enter_chain_stage(
+        quest_manager: &mut QuestManager,
+        chain_catalog: &QuestChainCatalog,
+        chain_id: &str,
+        stage_id: Option<&str>,
+        end_status: ChainStatus,
+        day: u32,
+    ) {
+        let Some(chain) = chain_catalog.get(chain_id) else {
+            return;
+        };
+        
+        let next_stage = stage_id.and_then(|stage_id| {
+            let stage = chain.stage(stage_id);
+            if stage.is_none() {
+                println!("故事任务 {} 缺少阶段 {}", chain_id, stage_id);
+            }
+            stage
+        });
+        
+        let Some(progress) = quest_manager.chains.iter_mut().find(|progress| progress.chain_id == chain_id) else {
+            return;
+        };
+        
+        match next_stage {
+            Some(stage) => {
+                progress.current_stage = Some(stage.id.clone());
+                println!("故事任务进展：{} - {}", chain.title.get(DEFAULT_LOCALE), stage.title.get(DEFAULT_LOCALE));
+                for quest in chain.stage_quests(stage, day) {
+                    quest_manager.add_quest(quest);
+                }
+            }
+            None => {
+                progress.current_stage = None;
+                progress.status = end_status;
+                match end_status {
+                    ChainStatus::Failed => println!("故事任务失败：{}", chain.title.get(DEFAULT_LOCALE)),
+                    _ => println!("故事任务完成：{}", chain.title.get(DEFAULT_LOCALE)),
+                }
+            }
         }
     }
     
-    fn get_item_description(item_type: ItemType) -> String {
-        match item_type {
-            ItemType::Apple => "新鲜的红苹果".to_string(),
-            ItemType::Carrot => "脆嫩的胡萝卜".to_string(),
-            ItemType::Bone => "小狗最爱的骨头".to_string(),
-            ItemType::Fish => "新鲜的鱼".to_string(),
-            ItemType::Milk => "营养丰富的牛奶".to_string(),
-            ItemType::Wheat => "金黄的小麦".to_string(),
-            ItemType::Flower => "美丽的花朵".to_string(),
-            ItemType::Corn => "甜玉米".to_string(),
-            ItemType::Hay => "干草，动物的食物".to_string(),
-            ItemType::Toy => "有趣的玩具".to_string(),
-            ItemType::Ribbon => "漂亮的丝带".to_string(),
-            ItemType::Bell => "清脆的铃铛".to_string(),
-            ItemType::Egg => "新鲜的鸡蛋".to_string(),
-            ItemType::Wool => "柔软的羊毛".to_string(),
-            ItemType::ApplePie => "美味的苹果派".to_string(),
-            ItemType::MilkBottle => "装在瓶子里的牛奶".to_string(),
-            _ => "普通物品".to_string(),
+    pub fn claim_mailbox(
+        actions: Res<ActionState>,
+        mut mailbox: ResMut<RewardMailbox>,
+        mut inventory: ResMut<Inventory>,
+        item_registry: Res<ItemRegistry>,
+    ) {
+        if !actions.just_pressed(InputAction::ClaimMailbox) {
+            return;
+        }
+        
+        if mailbox.items.is_empty() {
+            println!("邮箱是空的");
+            return;
+        }
+        
+        for item in mailbox.claim_into(&mut inventory) {
+            println!("从邮箱领取了 {}x{}", item_registry.name(item.item_type), item.quantity);
+        }
+        if !mailbox.items.is_empty() {
+            println!("物品栏已满，邮箱中还有 {} 种物品", mailbox.items.len());
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,389 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use crate::components::*;
+use crate::systems::animal_animation_systems as animal_animation;
+use crate::systems::animal_production_systems::ProductionIndicator;
+
+pub const SAVE_FILE_PATH: &str = "saves/savegame.json";
+
+// Bump this whenever the save layout changes and register a migration below
+pub const CURRENT_SAVE_VERSION: u32 = 3;
+
+// Each migration upgrades a raw save from the given version to the next one
+type Migration = fn(&mut Value);
+const MIGRATIONS: &[(u32, Migration)] = &[
+    (1, migrate_v1_add_world_seed),
+    (2, migrate_v2_move_day_to_calendar),
+];
+
+// v1 saves predate seeded generation, so there is no seed that reproduces their
+// map; any fixed seed at least keeps the farm stable from now on
+fn migrate_v1_add_world_seed(raw: &mut Value) {
+    raw["world_seed"] = Value::from(0u64);
+}
+
+// The day counter moved from the player stats into the time of day calendar
+fn migrate_v2_move_day_to_calendar(raw: &mut Value) {
+    let day = raw["player"]
+        .as_object_mut()
+        .and_then(|player| player.remove("day_survived"))
+        .unwrap_or(Value::from(1u32));
+    raw["time"]["day"] = day;
+}
+
+pub struct SaveSystems;
+
+type SavedAnimalQuery<'w, 's> = Query<'w, 's, (&'static Transform, &'static Animal, &'static AnimalAI, Option<&'static AnimalProduction>)>;
+
+// Sent by any system that wants the current game written to disk
+#[derive(Event)]
+pub struct SaveGameEvent;
+
+// Present from choosing Continue on the title menu until the save is restored
+#[derive(Resource)]
+pub struct LoadedSave(pub SaveData);
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Format(serde_json::Error),
+    MissingVersion,
+    UnsupportedVersion(u32),
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "文件读写失败: {}", err),
+            SaveError::Format(err) => write!(f, "存档格式错误: {}", err),
+            SaveError::MissingVersion => write!(f, "存档缺少版本号"),
+            SaveError::UnsupportedVersion(version) => write!(f, "不支持的存档版本: {}", version),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SaveData {
+    pub version: u32,
+    pub world_seed: u64,
+    pub player: SavedPlayer,
+    pub inventory: SavedInventory,
+    pub quests: QuestManager,
+    pub time: SavedTime,
+    pub weather: SavedWeather,
+    pub animals: Vec<SavedAnimal>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedPlayer {
+    pub position: [f32; 2],
+    pub hunger: f32,
+    pub fatigue: f32,
+    pub energy: f32,
+    pub coins: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedInventory {
+    pub items: Vec<Item>,
+    pub selected_index: usize,
+    pub capacity: usize,
+    #[serde(default)]
+    pub mailbox: Vec<Item>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedTime {
+    pub hour: u32,
+    pub minute: u32,
+    pub time_scale: f32,
+    pub day: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedWeather {
+    pub current_weather: WeatherType,
+    pub temperature: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedAnimal {
+    pub animal_type: AnimalType,
+    pub position: [f32; 3],
+    pub speed: f32,
+    pub friendship_level: u32,
+    pub hunger: f32,
+    pub state: AnimalState,
+    pub target_position: [f32; 2],
+    pub idle_timer_duration: f32,
+    pub idle_timer_elapsed: f32,
+    pub production: Option<SavedProduction>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedProduction {
+    pub timer_elapsed: f32,
+    pub can_produce: bool,
+    pub last_production_time: u32,
+}
+
+impl SaveSystems {
+    pub fn save_exists() -> bool {
+        Path::new(SAVE_FILE_PATH).exists()
+    }
+
+    // Called from the title menu; the farm is restored from it once Loading starts
+    pub fn load_saved_game(commands: &mut Commands) -> bool {
+        match Self::read_save_file(SAVE_FILE_PATH) {
+            Ok(save) => {
+                println!("读取存档成功 ({} 只动物, 种子 {})", save.animals.len(), save.world_seed);
+
+                // The farm must be regenerated from the seed it was saved with
+                commands.insert_resource(WorldSeed(save.world_seed));
+                commands.insert_resource(LoadedSave(save));
+                true
+            }
+            Err(err) => {
+                println!("读取存档失败: {}", err);
+                false
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore_saved_game(
+        mut commands: Commands,
+        loaded: Res<LoadedSave>,
+        species_registry: Res<SpeciesRegistry>,
+        mut player_stats: ResMut<PlayerStats>,
+        mut quest_manager: ResMut<QuestManager>,
+        mut time_of_day: ResMut<TimeOfDay>,
+        mut weather: ResMut<Weather>,
+        mut player_query: Query<&mut Transform, With<Player>>,
+    ) {
+        let save = &loaded.0;
+
+        // Player stats and position
+        player_stats.hunger = save.player.hunger;
+        player_stats.fatigue = save.player.fatigue;
+        player_stats.energy = save.player.energy;
+        player_stats.coins = save.player.coins;
+
+        if let Ok(mut player_transform) = player_query.single_mut() {
+            player_transform.translation.x = save.player.position[0];
+            player_transform.translation.y = save.player.position[1];
+        }
+
+        // Inventory
+        commands.insert_resource(Inventory {
+            items: save.inventory.items.clone(),
+            selected_index: save.inventory.selected_index,
+            capacity: save.inventory.capacity,
+            is_open: false,
+        });
+        commands.insert_resource(RewardMailbox {
+            items: save.inventory.mailbox.clone(),
+        });
+
+        // Quests
+        *quest_manager = save.quests.clone();
+
+        // Time and weather
+        time_of_day.hour = save.time.hour;
+        time_of_day.minute = save.time.minute;
+        time_of_day.time_scale = save.time.time_scale;
+        time_of_day.day = save.time.day.max(1);
+        time_of_day.current_phase = TimeOfDay::phase_for_hour(save.time.hour);
+
+        weather.current_weather = save.weather.current_weather;
+        weather.temperature = save.weather.temperature;
+
+        // Animals
+        for saved in &save.animals {
+            Self::spawn_saved_animal(&mut commands, species_registry.get(saved.animal_type), saved);
+        }
+
+        println!("存档已恢复: {} {}", time_of_day.get_date_string(), time_of_day.get_time_string());
+        commands.remove_resource::<LoadedSave>();
+    }
+
+    fn spawn_saved_animal(commands: &mut Commands, species: &SpeciesDefinition, saved: &SavedAnimal) {
+        let position = Vec3::from_array(saved.position);
+        let entity = animal_animation::AnimalAnimationSystems::spawn_animal_with_animation(
+            commands,
+            position,
+            species,
+        );
+
+        let mut idle_timer = Timer::from_seconds(saved.idle_timer_duration, TimerMode::Once);
+        idle_timer.set_elapsed(Duration::from_secs_f32(saved.idle_timer_elapsed));
+
+        commands.entity(entity).insert((
+            Animal {
+                animal_type: saved.animal_type,
+                speed: saved.speed,
+                friendship_level: saved.friendship_level,
+                hunger: saved.hunger,
+            },
+            AnimalAI {
+                state: saved.state,
+                target_position: Vec2::from_array(saved.target_position),
+                idle_timer,
+            },
+        ));
+
+        if let Some(saved_production) = &saved.production {
+            let mut production = AnimalProduction::new(species);
+            production.production_timer.set_elapsed(Duration::from_secs_f32(saved_production.timer_elapsed));
+            production.can_produce = saved_production.can_produce;
+            production.last_production_time = saved_production.last_production_time;
+
+            if production.can_produce {
+                commands.entity(entity).insert(ProductionIndicator {
+                    timer: Timer::from_seconds(30.0, TimerMode::Once),
+                });
+            }
+            commands.entity(entity).insert(production);
+        }
+    }
+
+    pub fn handle_save_input(
+        actions: Res<ActionState>,
+        mut save_events: EventWriter<SaveGameEvent>,
+    ) {
+        if actions.just_pressed(InputAction::QuickSave) {
+            save_events.write(SaveGameEvent);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn save_game(
+        mut save_events: EventReader<SaveGameEvent>,
+        seed: Res<WorldSeed>,
+        player_stats: Res<PlayerStats>,
+        inventory: Res<Inventory>,
+        mailbox: Res<RewardMailbox>,
+        quest_manager: Res<QuestManager>,
+        time_of_day: Res<TimeOfDay>,
+        weather: Res<Weather>,
+        player_query: Query<&Transform, With<Player>>,
+        animal_query: SavedAnimalQuery,
+    ) {
+        // Several requests in the same frame only need one write
+        if save_events.read().count() == 0 {
+            return;
+        }
+
+        let player_position = player_query
+            .single()
+            .map(|transform| [transform.translation.x, transform.translation.y])
+            .unwrap_or([0.0, 0.0]);
+
+        let animals = animal_query
+            .iter()
+            .map(|(transform, animal, ai, production)| SavedAnimal {
+                animal_type: animal.animal_type,
+                position: transform.translation.to_array(),
+                speed: animal.speed,
+                friendship_level: animal.friendship_level,
+                hunger: animal.hunger,
+                state: ai.state,
+                target_position: ai.target_position.to_array(),
+                idle_timer_duration: ai.idle_timer.duration().as_secs_f32(),
+                idle_timer_elapsed: ai.idle_timer.elapsed_secs(),
+                production: production.map(|production| SavedProduction {
+                    timer_elapsed: production.production_timer.elapsed_secs(),
+                    can_produce: production.can_produce,
+                    last_production_time: production.last_production_time,
+                }),
+            })
+            .collect();
+
+        let save = SaveData {
+            version: CURRENT_SAVE_VERSION,
+            world_seed: seed.0,
+            player: SavedPlayer {
+                position: player_position,
+                hunger: player_stats.hunger,
+                fatigue: player_stats.fatigue,
+                energy: player_stats.energy,
+                coins: player_stats.coins,
+            },
+            inventory: SavedInventory {
+                items: inventory.items.clone(),
+                selected_index: inventory.selected_index,
+                capacity: inventory.capacity,
+                mailbox: mailbox.items.clone(),
+            },
+            quests: quest_manager.clone(),
+            time: SavedTime {
+                hour: time_of_day.hour,
+                minute: time_of_day.minute,
+                time_scale: time_of_day.time_scale,
+                day: time_of_day.day,
+            },
+            weather: SavedWeather {
+                current_weather: weather.current_weather,
+                temperature: weather.temperature,
+            },
+            animals,
+        };
+
+        match Self::write_save_file(SAVE_FILE_PATH, &save) {
+            Ok(()) => println!("游戏已保存 ({})", SAVE_FILE_PATH),
+            Err(err) => println!("保存失败: {}", err),
+        }
+    }
+
+    pub fn read_save_file(path: &str) -> Result<SaveData, SaveError> {
+        let contents = fs::read_to_string(path).map_err(SaveError::Io)?;
+        let mut raw: Value = serde_json::from_str(&contents).map_err(SaveError::Format)?;
+
+        Self::migrate(&mut raw)?;
+        serde_json::from_value(raw).map_err(SaveError::Format)
+    }
+
+    pub fn write_save_file(path: &str, save: &SaveData) -> Result<(), SaveError> {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).map_err(SaveError::Io)?;
+        }
+
+        let contents = serde_json::to_string_pretty(save).map_err(SaveError::Format)?;
+
+        // Write to a temporary file first so a crash never leaves a half-written save
+        let temp_path = format!("{}.tmp", path);
+        fs::write(&temp_path, contents).map_err(SaveError::Io)?;
+        fs::rename(&temp_path, path).map_err(SaveError::Io)
+    }
+
+    // Upgrades a raw save in place, one version at a time, until it matches the current layout
+    pub fn migrate(raw: &mut Value) -> Result<(), SaveError> {
+        let mut version = raw
+            .get("version")
+            .and_then(Value::as_u64)
+            .ok_or(SaveError::MissingVersion)? as u32;
+
+        if version > CURRENT_SAVE_VERSION {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+
+        while version < CURRENT_SAVE_VERSION {
+            let migration = MIGRATIONS
+                .iter()
+                .find(|(from, _)| *from == version)
+                .map(|(_, migration)| migration)
+                .ok_or(SaveError::UnsupportedVersion(version))?;
+
+            migration(raw);
+            version += 1;
+            raw["version"] = Value::from(version);
+            println!("存档已升级到版本 {}", version);
+        }
+
+        Ok(())
+    }
+}
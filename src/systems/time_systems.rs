@@ -1,15 +1,22 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::config::GameConfig;
 
 pub struct TimeSystems;
 
+// Animals further than this from every barn stay put in bad weather
+const SHELTER_SEEK_RADIUS: f32 = 700.0;
+
 impl TimeSystems {
     pub fn setup_time_of_day(mut commands: Commands) {
         commands.insert_resource(TimeOfDay {
             hour: 8,
             minute: 0,
-            time_scale: 60.0, // 1 real second = 60 game minutes
+            time_scale: BASE_TIME_SCALE,
             current_phase: DayPhase::Morning,
+            day: 1,
+            minute_progress: 0.0,
+            paused: false,
         });
     }
 
@@ -17,106 +24,233 @@ impl TimeSystems {
         time: Res<Time>,
         mut time_of_day: ResMut<TimeOfDay>,
     ) {
-        // Update time
-        let delta_minutes = time.delta_secs() * time_of_day.time_scale;
-        time_of_day.minute += delta_minutes as u32;
+        if time_of_day.paused {
+            return;
+        }
+
+        // Accumulate fractional minutes so the clock runs at the same speed at any frame rate
+        time_of_day.minute_progress += time.delta_secs() * time_of_day.time_scale;
+        let whole_minutes = time_of_day.minute_progress.floor();
+        time_of_day.minute_progress -= whole_minutes;
+        time_of_day.minute += whole_minutes as u32;
 
         if time_of_day.minute >= 60 {
             time_of_day.hour += time_of_day.minute / 60;
             time_of_day.minute %= 60;
 
-            if time_of_day.hour >= 24 {
-                time_of_day.hour %= 24;
+            // Staying up past midnight starts the next day
+            while time_of_day.hour >= 24 {
+                time_of_day.hour -= 24;
+                time_of_day.advance_day();
             }
         }
 
         // Update day phase
-        time_of_day.current_phase = match time_of_day.hour {
-            6..=11 => DayPhase::Morning,
-            12..=16 => DayPhase::Noon,
-            17..=20 => DayPhase::Evening,
-            _ => DayPhase::Night,
+        time_of_day.current_phase = TimeOfDay::phase_for_hour(time_of_day.hour);
+    }
+
+    pub fn handle_time_controls(
+        actions: Res<ActionState>,
+        config: Res<GameConfig>,
+        mut time_of_day: ResMut<TimeOfDay>,
+    ) {
+        if actions.just_pressed(InputAction::ToggleTimePause) {
+            time_of_day.paused = !time_of_day.paused;
+            println!("{}", if time_of_day.paused { "时间已暂停" } else { "时间继续" });
+        }
+
+        // Step to the next slower or faster preset, starting from whatever speed is current
+        let current = time_of_day.time_scale;
+        let next_speed = if actions.just_pressed(InputAction::TimeFaster) {
+            config.time_speeds.iter().copied().filter(|&speed| speed > current).reduce(f32::min)
+        } else if actions.just_pressed(InputAction::TimeSlower) {
+            config.time_speeds.iter().copied().filter(|&speed| speed < current).reduce(f32::max)
+        } else if actions.just_pressed(InputAction::DebugTimeSpeed) {
+            Some(if current == config.debug_time_speed { BASE_TIME_SCALE } else { config.debug_time_speed })
+        } else {
+            None
         };
+
+        if let Some(speed) = next_speed {
+            time_of_day.time_scale = speed;
+            println!("时间速度: x{:.1} (每秒 {} 游戏分钟)", speed / BASE_TIME_SCALE, speed);
+        }
     }
 
-    pub fn apply_time_visual_effects(
+    // Compares the clock with last frame so ticking, sleeping and loading all go through one place.
+    // Every hour, phase and day passed gets its own event, even when several pass in one frame.
+    pub fn emit_time_events(
         time_of_day: Res<TimeOfDay>,
-        mut clear_color: ResMut<ClearColor>,
-        mut ambient_light: ResMut<AmbientLight>,
+        mut last_seen: Local<Option<(u32, u32, DayPhase)>>,
+        mut day_events: EventWriter<DayChanged>,
+        mut season_events: EventWriter<SeasonChanged>,
+        mut hour_events: EventWriter<HourChanged>,
+        mut phase_events: EventWriter<PhaseChanged>,
     ) {
-        let (bg_color, light_intensity, light_color) = match time_of_day.current_phase {
-            DayPhase::Morning => (
-                Color::srgb(0.8, 0.9, 1.0), // Light blue
-                0.8,
-                Color::srgb(1.0, 0.9, 0.7),
-            ),
-            DayPhase::Noon => (
-                Color::srgb(0.5, 0.8, 1.0), // Bright blue
-                1.0,
-                Color::srgb(1.0, 1.0, 0.9),
-            ),
-            DayPhase::Evening => (
-                Color::srgb(1.0, 0.6, 0.4), // Orange
-                0.6,
-                Color::srgb(1.0, 0.7, 0.4),
-            ),
-            DayPhase::Night => (
-                Color::srgb(0.1, 0.1, 0.3), // Dark blue
-                0.3,
-                Color::srgb(0.6, 0.6, 0.9),
-            ),
+        let current = (time_of_day.day, time_of_day.hour, time_of_day.current_phase);
+        let previous = last_seen.replace(current);
+
+        // A new or loaded game starts its own clock; nothing has passed on it yet
+        if time_of_day.is_added() {
+            return;
+        }
+        let Some((previous_day, previous_hour, previous_phase)) = previous else {
+            return;
         };
 
-        *clear_color = ClearColor(bg_color);
-        ambient_light.brightness = light_intensity;
-        ambient_light.color = light_color;
+        let mut phase = previous_phase;
+        let start = (previous_day - 1) * 24 + previous_hour;
+        let end = (time_of_day.day - 1) * 24 + time_of_day.hour;
+        for absolute_hour in start + 1..=end {
+            let hour = absolute_hour % 24;
+            hour_events.write(HourChanged { day: absolute_hour / 24 + 1, hour });
+
+            let hour_phase = TimeOfDay::phase_for_hour(hour);
+            if hour_phase != phase {
+                phase = hour_phase;
+                phase_events.write(PhaseChanged { phase });
+            }
+        }
+
+        if phase != time_of_day.current_phase {
+            phase_events.write(PhaseChanged { phase: time_of_day.current_phase });
+        }
+
+        for day in previous_day + 1..=time_of_day.day {
+            day_events.write(DayChanged { day });
+
+            if Season::for_day(day - 1) != Season::for_day(day) {
+                season_events.write(SeasonChanged {
+                    season: Season::for_day(day),
+                    year: TimeOfDay::year_for_day(day),
+                });
+            }
+        }
     }
 
-    pub fn update_animal_behavior_by_time(
+    pub fn announce_calendar_changes(
+        time_of_day: Res<TimeOfDay>,
+        mut day_events: EventReader<DayChanged>,
+        mut season_events: EventReader<SeasonChanged>,
+    ) {
+        for event in day_events.read() {
+            println!("新的一天开始了！第 {} 天 ({})", event.day, time_of_day.get_date_string());
+        }
+        for event in season_events.read() {
+            println!("第{}年{}季到了！", event.year, event.season.name());
+        }
+    }
+
+    // Tints the world through the overlay attached to the camera; the tint follows the
+    // fractional hour so dawn and dusk fade in instead of snapping between phases
+    pub fn update_day_night_tint(
+        time: Res<Time>,
         time_of_day: Res<TimeOfDay>,
-        mut query: Query<&mut AnimalAI>,
+        weather: Res<Weather>,
+        mut overlay_query: Query<(&mut Sprite, &mut DayNightOverlay)>,
+    ) {
+        let Ok((mut sprite, mut overlay)) = overlay_query.single_mut() else {
+            return;
+        };
+
+        // Ease weather changes in over a few seconds
+        let target_dimming = weather_dimming(weather.current_weather);
+        let blend = 1.0 - (-2.0 * time.delta_secs()).exp();
+        overlay.weather_dimming += (target_dimming - overlay.weather_dimming) * blend;
+
+        let (tint, alpha) = day_night_tint(time_of_day.get_hour_f32());
+
+        // Overcast skies grey out the tint and darken it further
+        let dimming = overlay.weather_dimming;
+        let grey_share = if alpha + dimming > 0.0 { dimming / (alpha + dimming) } else { 0.0 };
+        let color = tint.mix(&LinearRgba::rgb(0.25, 0.27, 0.3), grey_share);
+        let alpha = (alpha + dimming * (1.0 - alpha)).min(0.85);
+
+        sprite.color = Color::LinearRgba(color.with_alpha(alpha));
+    }
+
+    // Phase and weather changes redirect the animals once; in between, animal_idle_behavior
+    // keeps resting and sheltering animals from wandering off
+    pub fn update_animal_behavior_by_time(
+        weather: Res<Weather>,
+        mut phase_events: EventReader<PhaseChanged>,
+        mut weather_events: EventReader<WeatherChanged>,
+        shelter_query: Query<&Transform, (With<Shelter>, Without<AnimalAI>)>,
+        mut query: Query<(Entity, &Transform, &mut AnimalAI)>,
     ) {
-        for mut ai in query.iter_mut() {
-            match time_of_day.current_phase {
-                DayPhase::Morning => {
+        let phase = phase_events.read().last().map(|event| event.phase);
+        let new_weather = weather_events.read().last().map(|event| event.weather);
+        if phase.is_none() && new_weather.is_none() {
+            return;
+        }
+
+        let sheltering = new_weather.unwrap_or(weather.current_weather).sends_animals_to_shelter();
+        
+        for (entity, transform, mut ai) in query.iter_mut() {
+            // Rain and snow send animals under the nearest barn; followers stay with the player
+            if sheltering && ai.state != AnimalState::Following {
+                Self::seek_shelter(entity, transform, &mut ai, &shelter_query);
+                continue;
+            }
+            
+            match phase {
+                Some(DayPhase::Morning) if ai.state == AnimalState::Idle => {
                     // Animals are more active in the morning
-                    if ai.state == AnimalState::Idle && ai.idle_timer.finished() {
-                        ai.idle_timer.set_duration(std::time::Duration::from_secs_f32(2.0));
-                    }
-                }
-                DayPhase::Noon => {
-                    // Animals rest during noon
-                    if ai.state == AnimalState::Wandering {
-                        ai.state = AnimalState::Idle;
-                    }
+                    ai.idle_timer.set_duration(std::time::Duration::from_secs_f32(2.0));
+                    ai.idle_timer.reset();
                 }
-                DayPhase::Evening => {
-                    // Animals become active again
-                    if ai.state == AnimalState::Idle && ai.idle_timer.finished() {
-                        ai.state = AnimalState::Wandering;
-                    }
+                Some(DayPhase::Noon) | Some(DayPhase::Night) if ai.state != AnimalState::Following => {
+                    // Animals rest during noon and sleep at night
+                    ai.state = AnimalState::Idle;
+                    ai.target_position = transform.translation.truncate();
                 }
-                DayPhase::Night => {
-                    // Animals sleep at night
-                    if ai.state != AnimalState::Idle {
-                        ai.state = AnimalState::Idle;
-                    }
+                Some(DayPhase::Evening) if ai.state == AnimalState::Idle => {
+                    // Animals become active again and soon pick something to do
+                    ai.idle_timer.set_duration(std::time::Duration::from_secs_f32(0.5));
+                    ai.idle_timer.reset();
                 }
+                _ => {}
             }
         }
     }
-
-    pub fn display_time_info(time_of_day: Res<TimeOfDay>) {
-        // Log time information every minute
-        if time_of_day.minute % 10 == 0 {
-            let time_str = time_of_day.get_time_string();
-            let phase_str = match time_of_day.current_phase {
-                DayPhase::Morning => "早晨",
-                DayPhase::Noon => "中午",
-                DayPhase::Evening => "傍晚",
-                DayPhase::Night => "夜晚",
-            };
-            println!("游戏时间: {} ({})", time_str, phase_str);
+    
+    fn seek_shelter(
+        entity: Entity,
+        transform: &Transform,
+        ai: &mut AnimalAI,
+        shelter_query: &Query<&Transform, (With<Shelter>, Without<AnimalAI>)>,
+    ) {
+        let position = transform.translation.truncate();
+        let nearest = shelter_query
+            .iter()
+            .map(|shelter| shelter.translation.truncate())
+            .filter(|shelter| shelter.distance(position) < SHELTER_SEEK_RADIUS)
+            .min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)));
+        
+        let Some(shelter) = nearest else {
+            // Nowhere to go, so huddle down where they are
+            ai.target_position = position;
+            ai.state = AnimalState::Idle;
+            return;
+        };
+        
+        // Spread the animals out in front of the barn door instead of stacking them
+        let slot = entity.index() as f32;
+        let spot = shelter + Vec2::new((slot % 7.0 - 3.0) * 18.0, -70.0 - (slot / 7.0).floor() % 3.0 * 15.0);
+        ai.target_position = spot;
+        ai.state = if spot.distance(position) > 10.0 { AnimalState::Wandering } else { AnimalState::Idle };
+    }
+    
+    pub fn display_time_info(
+        mut hour_events: EventReader<HourChanged>,
+        mut phase_events: EventReader<PhaseChanged>,
+    ) {
+        // Log the time once per game hour
+        for event in hour_events.read() {
+            println!("游戏时间: 第 {} 天 {:02}:00 ({})", event.day, event.hour, TimeOfDay::phase_for_hour(event.hour).name());
+        }
+        for event in phase_events.read() {
+            println!("现在是{}了", event.phase.name());
         }
     }
 }
@@ -126,13 +260,112 @@ impl TimeOfDay {
         format!("{:02}:{:02}", self.hour, self.minute)
     }
 
+    // Game minutes that passed this frame, including any time skipped by sleeping
+    pub fn elapsed_minutes(&self, time: &Time, skipped: &mut EventReader<TimeSkipped>) -> f32 {
+        let skipped: f32 = skipped.read().map(|event| event.minutes).sum();
+        let ticked = if self.paused { 0.0 } else { time.delta_secs() * self.time_scale };
+        ticked + skipped
+    }
+
     pub fn get_hour_f32(&self) -> f32 {
-        self.hour as f32 + (self.minute as f32 / 60.0)
+        self.hour as f32 + ((self.minute as f32 + self.minute_progress) / 60.0)
+    }
+
+    pub fn phase_for_hour(hour: u32) -> DayPhase {
+        match hour {
+            6..=11 => DayPhase::Morning,
+            12..=16 => DayPhase::Noon,
+            17..=20 => DayPhase::Evening,
+            _ => DayPhase::Night,
+        }
+    }
+}
+
+// (hour, tint, opacity) keyframes for the overlay; the last one wraps to the first
+const DAY_NIGHT_KEYFRAMES: [(f32, [f32; 3], f32); 9] = [
+    (0.0, [0.02, 0.03, 0.15], 0.6),   // midnight
+    (4.5, [0.02, 0.03, 0.15], 0.6),
+    (5.5, [0.55, 0.3, 0.35], 0.35),   // dawn
+    (7.0, [1.0, 0.75, 0.5], 0.1),
+    (9.0, [1.0, 1.0, 1.0], 0.0),      // full daylight
+    (16.5, [1.0, 1.0, 1.0], 0.0),
+    (18.0, [1.0, 0.45, 0.15], 0.25),  // dusk
+    (19.5, [0.3, 0.15, 0.4], 0.45),
+    (21.0, [0.02, 0.03, 0.15], 0.6),
+];
+
+fn day_night_tint(hour: f32) -> (LinearRgba, f32) {
+    let hour = hour.rem_euclid(24.0);
+    let next_index = DAY_NIGHT_KEYFRAMES
+        .iter()
+        .position(|(key_hour, _, _)| *key_hour > hour)
+        .unwrap_or(0);
+    let previous_index = (next_index + DAY_NIGHT_KEYFRAMES.len() - 1) % DAY_NIGHT_KEYFRAMES.len();
+
+    let (from_hour, from_tint, from_alpha) = DAY_NIGHT_KEYFRAMES[previous_index];
+    let (mut to_hour, to_tint, to_alpha) = DAY_NIGHT_KEYFRAMES[next_index];
+    if to_hour <= from_hour {
+        to_hour += 24.0;
+    }
+
+    let t = ((hour - from_hour) / (to_hour - from_hour)).clamp(0.0, 1.0);
+    let from = LinearRgba::from(Color::srgb_from_array(from_tint));
+    let to = LinearRgba::from(Color::srgb_from_array(to_tint));
+    (from.mix(&to, t), from_alpha + (to_alpha - from_alpha) * t)
+}
+
+fn weather_dimming(weather: WeatherType) -> f32 {
+    match weather {
+        WeatherType::Sunny => 0.0,
+        WeatherType::Cloudy => 0.12,
+        WeatherType::Rainy => 0.25,
+        WeatherType::Snowy => 0.15,
+    }
+}
+
+// Steps the weather chain on every change-interval hour boundary that passed
+pub fn update_weather_system(
+    time_of_day: Res<TimeOfDay>,
+    climate: Res<Climate>,
+    seed: Res<WorldSeed>,
+    mut weather: ResMut<Weather>,
+    mut hour_events: EventReader<HourChanged>,
+    mut weather_events: EventWriter<WeatherChanged>,
+) {
+    let interval = climate.change_interval_hours;
+    let mut changed = weather.forecast.is_none();
+
+    for event in hour_events.read() {
+        let step = event.absolute_hour();
+        // Boundaries already stepped, e.g. before the game was saved, don't roll again
+        if step % interval != 0 || weather.last_change_hour.is_some_and(|last| step <= last) {
+            continue;
+        }
+
+        // Seeded per hour so the same world always gets the same weather
+        let mut rng = seed.rng(&format!("weather-hour-{}", step));
+        let next = climate.next_weather(&mut rng, Season::for_day(event.day), weather.current_weather);
+        if next != weather.current_weather {
+            println!("天气变为{}", next.name());
+            weather.current_weather = next;
+            weather_events.write(WeatherChanged { weather: next });
+        }
+        weather.last_change_hour = Some(step);
+        changed = true;
+    }
+
+    if changed {
+        weather.forecast = Some(climate.forecast(weather.current_weather, time_of_day.day, time_of_day.hour));
     }
 }
 
-pub fn update_weather_system(time: Res<Time>, mut weather: ResMut<Weather>) {
-    weather.update(time.delta());
+// The temperature follows the fractional hour along its daily curve, like the day-night tint
+pub fn update_temperature(
+    time_of_day: Res<TimeOfDay>,
+    climate: Res<Climate>,
+    mut weather: ResMut<Weather>,
+) {
+    weather.temperature = climate.temperature(time_of_day.season(), time_of_day.get_hour_f32(), weather.current_weather);
 }
 
 pub fn setup_weather(mut commands: Commands) {
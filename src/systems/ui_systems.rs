@@ -1,10 +1,30 @@
 use bevy::prelude::*;
-use bevy::ecs::system::ParamSet;
+use bevy::ecs::system::{ParamSet, SystemParam};
+use std::fs;
 use crate::components::*;
 
+// Needs to cover CJK; the font built into Bevy only has Latin glyphs
+pub const UI_FONT_PATH: &str = "assets/fonts/NotoSansSC-Regular.ttf";
+
 pub struct UISystems;
 
 impl UISystems {
+    // Replaces Bevy's default font, so every text uses it without naming a font
+    pub fn setup_ui_font(mut fonts: ResMut<Assets<Font>>) {
+        let bytes = match fs::read(UI_FONT_PATH) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                println!("未找到字体文件 {}，中文可能无法显示", UI_FONT_PATH);
+                return;
+            }
+        };
+
+        match Font::try_from_bytes(bytes) {
+            Ok(font) => fonts.insert(AssetId::default(), font),
+            Err(err) => println!("字体文件 {} 无效: {}", UI_FONT_PATH, err),
+        }
+    }
+
     pub fn setup_ui(mut commands: Commands) {
         // UI camera - removed to avoid conflicts with main camera
         // The main camera from camera.rs will handle both 2D and UI rendering
@@ -46,13 +66,14 @@ impl UISystems {
                             .with_children(|parent| {
                                 // Hunger label
                                 parent.spawn((
-                                    Text::new("饥饿值:"),
+                                    Text::new(""),
                                     TextFont {
                                         font_size: 16.0,
                                         ..default()
                                     },
                                     TextColor(Color::srgb(1.0, 1.0, 1.0)),
                                     PlayerStatusText { status_type: StatusType::Hunger },
+                                    LocalizedLabel("hud.hunger"),
                                 ));
                                 
                                 // Hunger progress bar background
@@ -76,13 +97,14 @@ impl UISystems {
                                 
                                 // Energy label
                                 parent.spawn((
-                                    Text::new("能量值:"),
+                                    Text::new(""),
                                     TextFont {
                                         font_size: 16.0,
                                         ..default()
                                     },
                                     TextColor(Color::srgb(1.0, 1.0, 1.0)),
                                     PlayerStatusText { status_type: StatusType::Energy },
+                                    LocalizedLabel("hud.energy"),
                                 ));
                                 
                                 // Energy progress bar background
@@ -118,7 +140,7 @@ impl UISystems {
                             })
                             .with_children(|parent| {
                                 // Create 8 inventory slots
-                                for i in 0..8 {
+                                for i in 0..usize::from(HOTBAR_SLOTS) {
                                     parent
                                         .spawn((
                                             Node {
@@ -132,6 +154,7 @@ impl UISystems {
                                             },
                                             BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
                                             BorderColor(Color::srgb(0.8, 0.8, 0.8)),
+                                            InventorySlotFrame { slot_index: i },
                                         ))
                                         .with_children(|parent| {
                                             // Item icon container with better layout
@@ -174,7 +197,7 @@ impl UISystems {
                                                 
                                                 // Item name
                                                 parent.spawn((
-                                                    Text::new("物品"),
+                                                    Text::new(""),
                                                     TextFont {
                                                         font_size: 10.0,
                                                         ..default()
@@ -223,7 +246,7 @@ impl UISystems {
                                 
                                 // Weather display
                                 parent.spawn((
-                                    Text::new("☀️ 晴天"),
+                                    Text::new(""),
                                     TextFont {
                                         font_size: 20.0,
                                         ..default()
@@ -234,7 +257,7 @@ impl UISystems {
                                 
                                 // Day display
                                 parent.spawn((
-                                    Text::new("第1天"),
+                                    Text::new(""),
                                     TextFont {
                                         font_size: 16.0,
                                         ..default()
@@ -242,6 +265,17 @@ impl UISystems {
                                     TextColor(Color::srgb(0.8, 0.8, 0.8)),
                                     DayDisplay,
                                 ));
+                                
+                                // World seed display
+                                parent.spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                                    SeedDisplay,
+                                ));
                             });
                     });
             });
@@ -265,6 +299,8 @@ impl UISystems {
     
     pub fn update_inventory_ui(
         inventory: Res<Inventory>,
+        item_registry: Res<ItemRegistry>,
+        localization: Res<Localization>,
         mut inventory_query: ParamSet<(
             Query<(&mut Text, &InventorySlot)>,
             Query<(&mut Text, &InventoryItemCount)>,
@@ -275,8 +311,7 @@ impl UISystems {
         // Update inventory icons
         for (mut text, slot) in inventory_query.p0().iter_mut() {
             if let Some(item) = inventory.items.get(slot.slot_index) {
-                let symbol = item.get_item_symbol();
-                *text = Text::new(symbol);
+                *text = Text::new(item_registry.get(item.item_type).icon.clone());
             } else {
                 *text = Text::new("□");
             }
@@ -294,7 +329,7 @@ impl UISystems {
         // Update item colors
         for (mut bg_color, color_slot) in inventory_query.p2().iter_mut() {
             if let Some(item) = inventory.items.get(color_slot.slot_index) {
-                bg_color.0 = item.get_item_color();
+                bg_color.0 = item_registry.get(item.item_type).color();
             } else {
                 bg_color.0 = Color::srgb(0.2, 0.2, 0.2); // Default dark gray
             }
@@ -303,10 +338,9 @@ impl UISystems {
         // Update item names
         for (mut text, name_slot) in inventory_query.p3().iter_mut() {
             if let Some(item) = inventory.items.get(name_slot.slot_index) {
-                let name = item.get_item_name();
-                *text = Text::new(name);
+                *text = Text::new(localization.text(&item_registry.get(item.item_type).name));
             } else {
-                *text = Text::new("Empty");
+                *text = Text::new(localization.get("hud.empty_slot"));
             }
         }
     }
@@ -314,6 +348,7 @@ impl UISystems {
     pub fn update_time_weather_ui(
         time_of_day: Res<TimeOfDay>,
         weather: Res<Weather>,
+        localization: Res<Localization>,
         mut text_queries: ParamSet<(
             Query<&mut Text, With<TimeDisplay>>,
             Query<&mut Text, With<WeatherDisplay>>,
@@ -322,7 +357,14 @@ impl UISystems {
     ) {
         // Update time display
         if let Ok(mut time_text) = text_queries.p0().single_mut() {
-            *time_text = Text::new(format!("{:02}:{:02}", time_of_day.hour, time_of_day.minute));
+            let speed = if time_of_day.paused {
+                format!(" {}", localization.get("hud.paused"))
+            } else if time_of_day.time_scale != BASE_TIME_SCALE {
+                format!(" x{:.1}", time_of_day.time_scale / BASE_TIME_SCALE)
+            } else {
+                String::new()
+            };
+            *time_text = Text::new(format!("{:02}:{:02}{}", time_of_day.hour, time_of_day.minute, speed));
         }
         
         // Update weather display
@@ -333,53 +375,269 @@ impl UISystems {
                 WeatherType::Rainy => "🌧️",
                 WeatherType::Snowy => "❄️",
             };
-            *weather_text = Text::new(format!("{} {}", weather_icon, weather.get_weather_name()));
+            let forecast = weather.forecast
+                .map(|forecast| format!("\n{}", localization.format("hud.forecast", &[
+                    ("weather", &localization.weather_name(forecast.weather)),
+                    ("chance", &format!("{:.0}", forecast.chance * 100.0)),
+                    ("low", &format!("{:.0}", forecast.low)),
+                    ("high", &format!("{:.0}", forecast.high)),
+                ])))
+                .unwrap_or_default();
+            *weather_text = Text::new(format!("{} {} {:.0}°C{}", weather_icon, localization.weather_name(weather.current_weather), weather.temperature, forecast));
         }
         
         // Update day display
         if let Ok(mut day_text) = text_queries.p2().single_mut() {
-            *day_text = Text::new(format!("第{}天", time_of_day.hour / 24 + 1));
+            *day_text = Text::new(localization.date(&time_of_day));
+        }
+    }
+    
+    pub fn update_seed_ui(
+        seed: Res<WorldSeed>,
+        localization: Res<Localization>,
+        mut seed_query: Query<&mut Text, With<SeedDisplay>>,
+    ) {
+        if let Ok(mut seed_text) = seed_query.single_mut() {
+            *seed_text = Text::new(localization.format("hud.seed", &[("seed", &seed.0)]));
+        }
+    }
+    
+    pub fn update_localized_labels(
+        localization: Res<Localization>,
+        mut label_query: Query<(&mut Text, &LocalizedLabel)>,
+    ) {
+        for (mut text, label) in label_query.iter_mut() {
+            **text = localization.get(label.0).to_string();
+        }
+    }
+    
+    // Prompts are refilled when they appear, and when the language or the input device changes
+    pub fn update_prompt_labels(
+        prompts: InputPrompts,
+        mut label_query: Query<(&mut Text, Ref<PromptLabel>)>,
+    ) {
+        let refresh_all = prompts.is_changed();
+        for (mut text, label) in label_query.iter_mut() {
+            if refresh_all || label.is_added() {
+                **text = prompts.format(label.key, label.inputs);
+            }
+        }
+    }
+    
+    // Selection itself is handled by InventorySystems::handle_inventory_input
+    pub fn highlight_inventory_selection(
+        inventory: Res<Inventory>,
+        mut inventory_slot_query: Query<(&InventorySlotFrame, &mut BackgroundColor)>,
+    ) {
+        highlight_selected_slot(&mut inventory_slot_query, inventory.selected_index);
+    }
+    
+    pub fn setup_quest_ui(mut commands: Commands) {
+        // Pinned quest tracker in the top right corner
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(10.0),
+                    right: Val::Px(10.0),
+                    width: Val::Px(240.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                QuestTracker,
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 1.0, 1.0)),
+                    QuestTrackerText,
+                ));
+                
+                // Tracker progress bar background
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(220.0),
+                            height: Val::Px(8.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Node {
+                                width: Val::Px(0.0),
+                                height: Val::Px(8.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(1.0, 0.8, 0.2)),
+                            QuestTrackerBar,
+                        ));
+                    });
+            });
+        
+        // Journal panel, hidden until toggled
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(40.0),
+                    left: Val::Percent(25.0),
+                    width: Val::Percent(50.0),
+                    max_height: Val::Percent(70.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.0),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    overflow: Overflow::clip(),
+                    display: Display::None,
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.1, 0.08, 0.05, 0.92)),
+                BorderColor(Color::srgb(0.8, 0.7, 0.5)),
+                QuestJournalPanel,
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.9, 0.6)),
+                    PromptLabel {
+                        key: "quest.journal_title",
+                        inputs: &[
+                            ("journal", InputContext::Gameplay, InputAction::ToggleQuestJournal),
+                            ("cycle", InputContext::Gameplay, InputAction::CycleTrackedQuest),
+                        ],
+                    },
+                ));
+                
+                parent.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    QuestJournalContent,
+                ));
+            });
+    }
+    
+    pub fn handle_quest_journal_input(
+        actions: Res<ActionState>,
+        mut journal: ResMut<QuestJournal>,
+        quest_manager: Res<QuestManager>,
+    ) {
+        if actions.just_pressed(InputAction::ToggleQuestJournal) {
+            journal.is_open = !journal.is_open;
+        }
+        
+        // Cycle the pinned quest through the active ones
+        if actions.just_pressed(InputAction::CycleTrackedQuest) && !quest_manager.active_quests.is_empty() {
+            let active = &quest_manager.active_quests;
+            let next_index = journal.pinned.as_ref()
+                .and_then(|id| active.iter().position(|quest| &quest.id == id))
+                .map(|index| (index + 1) % active.len())
+                .unwrap_or(0);
+            journal.pinned = Some(active[next_index].id.clone());
         }
     }
     
-    pub fn handle_inventory_input(
-        keyboard: Res<ButtonInput<KeyCode>>,
-        mut inventory: ResMut<Inventory>,
-        mut inventory_slot_query: Query<(&InventorySlot, &mut BackgroundColor)>,
+    pub fn update_quest_journal(
+        mut commands: Commands,
+        journal: Res<QuestJournal>,
+        quest_manager: Res<QuestManager>,
+        quest_text: QuestText,
+        mut panel_query: Query<&mut Node, With<QuestJournalPanel>>,
+        content_query: Query<Entity, With<QuestJournalContent>>,
     ) {
-        // Number keys 1-8 to select inventory slots
-        for i in 1..=8 {
-            if keyboard.just_pressed(KeyCode::Digit1) && i == 1 {
-                inventory.selected_index = 0;
-                highlight_selected_slot(&mut inventory_slot_query, 0);
-            } else if keyboard.just_pressed(KeyCode::Digit2) && i == 2 {
-                inventory.selected_index = 1;
-                highlight_selected_slot(&mut inventory_slot_query, 1);
-            } else if keyboard.just_pressed(KeyCode::Digit3) && i == 3 {
-                inventory.selected_index = 2;
-                highlight_selected_slot(&mut inventory_slot_query, 2);
-            } else if keyboard.just_pressed(KeyCode::Digit4) && i == 4 {
-                inventory.selected_index = 3;
-                highlight_selected_slot(&mut inventory_slot_query, 3);
-            } else if keyboard.just_pressed(KeyCode::Digit5) && i == 5 {
-                inventory.selected_index = 4;
-                highlight_selected_slot(&mut inventory_slot_query, 4);
-            } else if keyboard.just_pressed(KeyCode::Digit6) && i == 6 {
-                inventory.selected_index = 5;
-                highlight_selected_slot(&mut inventory_slot_query, 5);
-            } else if keyboard.just_pressed(KeyCode::Digit7) && i == 7 {
-                inventory.selected_index = 6;
-                highlight_selected_slot(&mut inventory_slot_query, 6);
-            } else if keyboard.just_pressed(KeyCode::Digit8) && i == 8 {
-                inventory.selected_index = 7;
-                highlight_selected_slot(&mut inventory_slot_query, 7);
+        if let Ok(mut panel) = panel_query.single_mut() {
+            panel.display = if journal.is_open { Display::Flex } else { Display::None };
+        }
+        
+        // Only rebuild the list while it can be seen
+        let Ok(content) = content_query.single() else {
+            return;
+        };
+        if !journal.is_open {
+            return;
+        }
+        
+        commands.entity(content).despawn_related::<Children>();
+        let localization = &quest_text.localization;
+        commands.entity(content).with_children(|parent| {
+            spawn_journal_heading(parent, localization.get("quest.active"));
+            if quest_manager.active_quests.is_empty() {
+                spawn_journal_note(parent, localization.get("quest.none"));
+            }
+            for quest in &quest_manager.active_quests {
+                let pinned = journal.pinned.as_deref() == Some(quest.id.as_str());
+                spawn_journal_entry(parent, quest, QuestEntryStatus::Active, pinned, &quest_text);
+            }
+            
+            // Only the most recent history fits on screen
+            spawn_journal_heading(parent, localization.get("quest.completed"));
+            if quest_manager.completed_quests.is_empty() {
+                spawn_journal_note(parent, localization.get("quest.none"));
+            }
+            for quest in quest_manager.completed_quests.iter().rev().take(JOURNAL_HISTORY_LIMIT) {
+                spawn_journal_entry(parent, quest, QuestEntryStatus::Completed, false, &quest_text);
+            }
+            
+            spawn_journal_heading(parent, localization.get("quest.failed"));
+            if quest_manager.failed_quests.is_empty() {
+                spawn_journal_note(parent, localization.get("quest.none"));
+            }
+            for quest in quest_manager.failed_quests.iter().rev().take(JOURNAL_HISTORY_LIMIT) {
+                spawn_journal_entry(parent, quest, QuestEntryStatus::Failed, false, &quest_text);
             }
+        });
+    }
+    
+    pub fn update_quest_tracker(
+        journal: Res<QuestJournal>,
+        quest_manager: Res<QuestManager>,
+        quest_text: QuestText,
+        prompts: InputPrompts,
+        mut text_query: Query<&mut Text, With<QuestTrackerText>>,
+        mut bar_query: Query<&mut Node, With<QuestTrackerBar>>,
+    ) {
+        // Fall back to the first story or one-off quest, then to any daily
+        let tracked = journal.pinned.as_ref()
+            .and_then(|id| quest_manager.active_quests.iter().find(|quest| &quest.id == id))
+            .or_else(|| quest_manager.active_quests.iter().find(|quest| !quest.is_daily))
+            .or_else(|| quest_manager.active_quests.first());
+        
+        if let Ok(mut text) = text_query.single_mut() {
+            *text = match tracked {
+                Some(quest) => {
+                    let (title, description) = quest_text.get(quest);
+                    Text::new(format!("{}\n{}\n{}/{}", title, description, quest.progress, quest.required_amount))
+                }
+                None => Text::new(prompts.format("quest.tracker_empty", &[
+                    ("journal", InputContext::Gameplay, InputAction::ToggleQuestJournal),
+                ])),
+            };
+        }
+        
+        if let Ok(mut bar) = bar_query.single_mut() {
+            let fraction = tracked.map(quest_fraction).unwrap_or(0.0);
+            bar.width = Val::Px(220.0 * fraction);
         }
     }
 }
 
 fn highlight_selected_slot(
-    inventory_slot_query: &mut Query<(&InventorySlot, &mut BackgroundColor)>,
+    inventory_slot_query: &mut Query<(&InventorySlotFrame, &mut BackgroundColor)>,
     selected_index: usize,
 ) {
     for (slot, mut background_color) in inventory_slot_query.iter_mut() {
@@ -391,6 +649,180 @@ fn highlight_selected_slot(
     }
 }
 
+const JOURNAL_HISTORY_LIMIT: usize = 8;
+
+#[derive(Clone, Copy, PartialEq)]
+enum QuestEntryStatus {
+    Active,
+    Completed,
+    Failed,
+}
+
+fn quest_fraction(quest: &Quest) -> f32 {
+    if quest.required_amount == 0 {
+        1.0
+    } else {
+        (quest.progress as f32 / quest.required_amount as f32).min(1.0)
+    }
+}
+
+fn reward_summary(reward: &QuestReward, quest_text: &QuestText) -> String {
+    let localization = &quest_text.localization;
+    let mut parts = vec![localization.format("quest.reward_coins", &[("coins", &reward.coins)])];
+    for (item_type, quantity) in &reward.items {
+        parts.push(format!("{}x{}", localization.text(&quest_text.item_registry.get(*item_type).name), quantity));
+    }
+    if reward.friendship_bonus > 0 {
+        parts.push(localization.format("quest.reward_friendship", &[("amount", &reward.friendship_bonus)]));
+    }
+    parts.join(" ")
+}
+
+fn spawn_journal_heading(parent: &mut ChildSpawnerCommands, title: &str) {
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.8, 0.5)),
+    ));
+}
+
+fn spawn_journal_note(parent: &mut ChildSpawnerCommands, note: &str) {
+    parent.spawn((
+        Text::new(note),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+    ));
+}
+
+fn spawn_journal_entry(
+    parent: &mut ChildSpawnerCommands,
+    quest: &Quest,
+    status: QuestEntryStatus,
+    pinned: bool,
+    quest_text: &QuestText,
+) {
+    let localization = &quest_text.localization;
+    let (status_text, bar_color) = match status {
+        QuestEntryStatus::Active => (String::new(), Color::srgb(1.0, 0.8, 0.2)),
+        QuestEntryStatus::Completed => (format!(" [{}]", localization.get("quest.completed")), Color::srgb(0.3, 0.9, 0.3)),
+        QuestEntryStatus::Failed => (format!(" [{}]", localization.get("quest.failed")), Color::srgb(0.9, 0.3, 0.3)),
+    };
+    let deadline_text = match quest.deadline {
+        Some(day) => localization.format("quest.deadline", &[("day", &day)]),
+        None => localization.get("quest.no_deadline").to_string(),
+    };
+    let (title, description) = quest_text.get(quest);
+    
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(2.0),
+            padding: UiRect::left(Val::Px(8.0)),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("{}{}{}", if pinned { "▶ " } else { "" }, title, status_text)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 1.0, 1.0)),
+            ));
+            
+            parent.spawn((
+                Text::new(format!("{}  ({}/{})  {}", description, quest.progress, quest.required_amount, deadline_text)),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+            
+            // Progress bar
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(6.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Node {
+                            width: Val::Px(200.0 * quest_fraction(quest)),
+                            height: Val::Px(6.0),
+                            ..default()
+                        },
+                        BackgroundColor(bar_color),
+                    ));
+                });
+            
+            parent.spawn((
+                Text::new(localization.format("quest.reward", &[("reward", &reward_summary(&quest.reward, quest_text))])),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.4)),
+            ));
+        });
+}
+
+// Everything needed to show quest text in the current language
+#[derive(SystemParam)]
+pub struct QuestText<'w> {
+    pub localization: Res<'w, Localization>,
+    pub item_registry: Res<'w, ItemRegistry>,
+    quest_catalog: Res<'w, QuestCatalog>,
+    chain_catalog: Res<'w, QuestChainCatalog>,
+}
+
+impl QuestText<'_> {
+    // Title and description
+    pub fn get(&self, quest: &Quest) -> (String, String) {
+        localized_quest_text(quest, &self.quest_catalog, &self.chain_catalog, self.localization.locale())
+    }
+}
+
+// Fills `{name}` placeholders in UI strings with the bound key or gamepad button
+// of the device the player used last
+#[derive(SystemParam)]
+pub struct InputPrompts<'w> {
+    bindings: Res<'w, InputBindings>,
+    device: Res<'w, InputDevice>,
+    localization: Res<'w, Localization>,
+}
+
+impl InputPrompts<'_> {
+    pub fn format(&self, key: &str, inputs: &[PromptInput]) -> String {
+        let labels: Vec<(&str, String)> = inputs
+            .iter()
+            .map(|(name, context, action)| (*name, self.bindings.label(*context, *action, *self.device)))
+            .collect();
+        let args: Vec<(&str, &dyn std::fmt::Display)> = labels
+            .iter()
+            .map(|(name, label)| (*name, label as &dyn std::fmt::Display))
+            .collect();
+        self.localization.format(key, &args)
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.device.is_changed() || self.localization.is_changed()
+    }
+}
+
+// Placeholder name and the action whose binding fills it
+pub type PromptInput = (&'static str, InputContext, InputAction);
+
 // UI component markers
 #[derive(Component)]
 pub struct PlayerStatusText {
@@ -414,6 +846,11 @@ pub struct InventorySlot {
     pub slot_index: usize,
 }
 
+#[derive(Component)]
+pub struct InventorySlotFrame {
+    pub slot_index: usize,
+}
+
 #[derive(Component)]
 pub struct InventoryItemCount {
     pub slot_index: usize,
@@ -436,4 +873,40 @@ pub struct TimeDisplay;
 pub struct WeatherDisplay;
 
 #[derive(Component)]
-pub struct DayDisplay;
\ No newline at end of file
+pub struct DayDisplay;
+
+#[derive(Component)]
+pub struct SeedDisplay;
+
+#[derive(Component)]
+pub struct QuestTracker;
+
+#[derive(Component)]
+pub struct QuestTrackerText;
+
+#[derive(Component)]
+pub struct QuestTrackerBar;
+
+#[derive(Component)]
+pub struct QuestJournalPanel;
+
+#[derive(Component)]
+pub struct QuestJournalContent;
+
+// Journal visibility and the quest pinned to the HUD tracker
+#[derive(Resource, Default)]
+pub struct QuestJournal {
+    pub is_open: bool,
+    pub pinned: Option<String>,
+}
+
+// Static text whose string is looked up again whenever the language changes
+#[derive(Component)]
+pub struct LocalizedLabel(pub &'static str);
+
+// Static text naming buttons, e.g. "press {confirm}"
+#[derive(Component)]
+pub struct PromptLabel {
+    pub key: &'static str,
+    pub inputs: &'static [PromptInput],
+}
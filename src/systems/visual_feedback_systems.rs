@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::interaction_systems::InteractionSystems;
 
 pub struct VisualFeedbackSystems;
 
@@ -128,6 +129,9 @@ impl VisualFeedbackSystems {
     pub fn show_interaction_prompts(
         mut query: Query<(&Transform, &Interactable, &Animal), Without<Player>>,
         player_query: Query<&Transform, With<Player>>,
+        inventory: Res<Inventory>,
+        item_registry: Res<ItemRegistry>,
+        species_registry: Res<SpeciesRegistry>,
     ) {
         let player_transform = match player_query.single() {
             Ok(transform) => transform,
@@ -141,27 +145,14 @@ impl VisualFeedbackSystems {
             let distance = (player_pos - animal_pos).length();
             
             if distance <= interactable.interaction_range {
-                let animal_name = Self::get_animal_name(animal.animal_type);
-                let prompt = match interactable.interaction_type {
-                    InteractionType::Feed => format!("按空格键喂食{}", animal_name),
-                    InteractionType::Pet => format!("按空格键抚摸{}", animal_name),
-                    InteractionType::Play => format!("按空格键和{}玩耍", animal_name),
+                let animal_name = species_registry.name(animal.animal_type);
+                let prompt = match InteractionSystems::interaction_for(&inventory, &item_registry, animal.animal_type) {
+                    InteractionType::Feed => format!("按空格键喂食{}, 按 R 键和它玩耍", animal_name),
+                    InteractionType::Pet => format!("按空格键抚摸{}, 按 R 键和它玩耍", animal_name),
+                    InteractionType::Play => format!("按 R 键和{}玩耍", animal_name),
                 };
                 println!("{}", prompt);
             }
         }
     }
-    
-    fn get_animal_name(animal_type: AnimalType) -> &'static str {
-        match animal_type {
-            AnimalType::Dog => "小狗",
-            AnimalType::Cat => "小猫",
-            AnimalType::Chicken => "小鸡",
-            AnimalType::Cow => "奶牛",
-            AnimalType::Sheep => "小羊",
-            AnimalType::Pig => "小猪",
-            AnimalType::Duck => "小鸭",
-            AnimalType::Horse => "小马",
-        }
-    }
 }
\ No newline at end of file
@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::camera::MainCamera;
+use crate::components::*;
+
+// Particles are pooled; intensity decides how many of them are shown
+const PARTICLE_POOL_SIZE: usize = 400;
+// Area around the camera the particle field covers, a bit larger than the view
+const PARTICLE_FIELD: Vec2 = Vec2::new(1800.0, 1000.0);
+const CLOUD_COUNT: usize = 6;
+const CLOUD_FIELD: Vec2 = Vec2::new(2800.0, 1800.0);
+const CLOUD_WIND: Vec2 = Vec2::new(18.0, 4.0);
+
+pub struct WeatherEffectsSystems;
+
+impl WeatherEffectsSystems {
+    pub fn setup_weather_effects(mut commands: Commands) {
+        let mut rng = rand::rng();
+
+        for index in 0..PARTICLE_POOL_SIZE {
+            commands.spawn((
+                Sprite {
+                    color: Color::NONE,
+                    custom_size: Some(Vec2::new(2.0, 14.0)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 850.0),
+                Visibility::Hidden,
+                WeatherParticle {
+                    index,
+                    offset: Self::random_point(&mut rng, PARTICLE_FIELD),
+                    speed: rng.random_range(0.8..1.2),
+                },
+            ));
+        }
+
+        // Cloud shadows drift across the world, so they live in world space
+        for _ in 0..CLOUD_COUNT {
+            let size = Vec2::new(rng.random_range(350.0..600.0), rng.random_range(200.0..320.0));
+            commands.spawn((
+                Sprite {
+                    color: Color::NONE,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                Transform::from_translation(Self::random_point(&mut rng, CLOUD_FIELD).extend(40.0)),
+                CloudShadow {
+                    drift: CLOUD_WIND * rng.random_range(0.8..1.3),
+                },
+            ));
+        }
+    }
+
+    // Follows the current weather in game time, so pausing also freezes snow and puddles
+    pub fn update_weather_effects(
+        time: Res<Time>,
+        time_of_day: Res<TimeOfDay>,
+        mut skipped_events: EventReader<TimeSkipped>,
+        weather: Res<Weather>,
+        mut effects: ResMut<WeatherEffects>,
+    ) {
+        let game_hours = time_of_day.elapsed_minutes(&time, &mut skipped_events) / 60.0;
+        if game_hours == 0.0 {
+            return;
+        }
+
+        let (target_intensity, target_clouds) = match weather.current_weather {
+            WeatherType::Sunny => (0.0, 0.0),
+            WeatherType::Cloudy => (0.0, 1.0),
+            WeatherType::Rainy => (1.0, 0.8),
+            WeatherType::Snowy => (0.8, 0.6),
+        };
+
+        // Keep the last kind of precipitation while it fades out
+        match weather.current_weather {
+            WeatherType::Rainy => effects.falling_snow = false,
+            WeatherType::Snowy => effects.falling_snow = true,
+            _ => {}
+        }
+
+        // Reach the new weather within about half a game hour
+        let blend = 1.0 - (-6.0 * game_hours).exp();
+        effects.intensity += (target_intensity - effects.intensity) * blend;
+        effects.cloud_cover += (target_clouds - effects.cloud_cover) * blend;
+
+        // Snow settles over ~6 hours and melts above freezing over ~12
+        if effects.falling_snow && effects.intensity > 0.1 {
+            effects.snow_cover += game_hours / 6.0 * effects.intensity;
+        } else if weather.temperature > 0.0 {
+            effects.snow_cover -= game_hours / 12.0;
+        }
+        effects.snow_cover = effects.snow_cover.clamp(0.0, 1.0);
+
+        // Puddles fill over ~2 hours of rain and dry over ~6
+        if !effects.falling_snow && effects.intensity > 0.1 {
+            effects.wetness += game_hours / 2.0 * effects.intensity;
+        } else {
+            effects.wetness -= game_hours / 6.0;
+        }
+        effects.wetness = effects.wetness.clamp(0.0, 1.0);
+    }
+
+    pub fn animate_weather_particles(
+        time: Res<Time>,
+        effects: Res<WeatherEffects>,
+        camera_query: Query<&Transform, (With<MainCamera>, Without<WeatherParticle>)>,
+        mut particle_query: Query<(&mut Transform, &mut Sprite, &mut Visibility, &mut WeatherParticle)>,
+    ) {
+        let Ok(camera_transform) = camera_query.single() else {
+            return;
+        };
+        let camera_position = camera_transform.translation.truncate();
+        let active = (effects.intensity * PARTICLE_POOL_SIZE as f32).round() as usize;
+        let elapsed = time.elapsed_secs();
+
+        for (mut transform, mut sprite, mut visibility, mut particle) in particle_query.iter_mut() {
+            if particle.index >= active {
+                *visibility = Visibility::Hidden;
+                continue;
+            }
+            *visibility = Visibility::Visible;
+
+            // Rain falls fast at a slant, snow drifts down and sways
+            let velocity = if effects.falling_snow {
+                let sway = (elapsed * 1.5 + particle.index as f32).sin() * 30.0;
+                Vec2::new(sway, -70.0)
+            } else {
+                Vec2::new(-120.0, -650.0)
+            } * particle.speed;
+            particle.offset += velocity * time.delta_secs();
+
+            // Wrap around the field so particles keep falling through the view
+            let half = PARTICLE_FIELD / 2.0;
+            particle.offset.x = (particle.offset.x + half.x).rem_euclid(PARTICLE_FIELD.x) - half.x;
+            particle.offset.y = (particle.offset.y + half.y).rem_euclid(PARTICLE_FIELD.y) - half.y;
+
+            transform.translation = (camera_position + particle.offset).extend(850.0);
+            if effects.falling_snow {
+                sprite.color = Color::srgba(1.0, 1.0, 1.0, 0.9);
+                sprite.custom_size = Some(Vec2::splat(4.0 * particle.speed));
+                transform.rotation = Quat::IDENTITY;
+            } else {
+                sprite.color = Color::srgba(0.7, 0.8, 1.0, 0.6);
+                sprite.custom_size = Some(Vec2::new(2.0, 14.0 * particle.speed));
+                transform.rotation = Quat::from_rotation_z(velocity.x.atan2(-velocity.y));
+            }
+        }
+    }
+
+    pub fn update_cloud_shadows(
+        time: Res<Time>,
+        effects: Res<WeatherEffects>,
+        camera_query: Query<&Transform, (With<MainCamera>, Without<CloudShadow>)>,
+        mut cloud_query: Query<(&mut Transform, &mut Sprite, &CloudShadow)>,
+    ) {
+        let Ok(camera_transform) = camera_query.single() else {
+            return;
+        };
+        let camera_position = camera_transform.translation.truncate();
+        let alpha = effects.cloud_cover * 0.18;
+
+        for (mut transform, mut sprite, cloud) in cloud_query.iter_mut() {
+            sprite.color = Color::srgba(0.05, 0.05, 0.1, alpha);
+
+            // Clouds blown out of the field re-enter on the opposite side of the camera
+            let half = CLOUD_FIELD / 2.0;
+            let mut relative = transform.translation.truncate() + cloud.drift * time.delta_secs() - camera_position;
+            relative.x = (relative.x + half.x).rem_euclid(CLOUD_FIELD.x) - half.x;
+            relative.y = (relative.y + half.y).rem_euclid(CLOUD_FIELD.y) - half.y;
+            transform.translation = (camera_position + relative).extend(40.0);
+        }
+    }
+
+    pub fn apply_snow_cover(
+        effects: Res<WeatherEffects>,
+        mut last_applied: Local<f32>,
+        mut query: Query<(&mut Sprite, &SnowCover)>,
+    ) {
+        // Thousands of grass sprites, so only recolor once the cover has visibly changed
+        let fully_melted = effects.snow_cover == 0.0 && *last_applied != 0.0;
+        if (effects.snow_cover - *last_applied).abs() < 0.02 && !fully_melted {
+            return;
+        }
+        *last_applied = effects.snow_cover;
+
+        let snow = LinearRgba::rgb(0.95, 0.96, 1.0);
+        for (mut sprite, cover) in query.iter_mut() {
+            let base = LinearRgba::from(cover.base_color);
+            sprite.color = Color::LinearRgba(base.mix(&snow, effects.snow_cover * 0.85));
+        }
+    }
+
+    pub fn update_puddles(
+        effects: Res<WeatherEffects>,
+        mut query: Query<(&mut Sprite, &Puddle)>,
+    ) {
+        for (mut sprite, puddle) in query.iter_mut() {
+            sprite.color = Color::srgba(0.35, 0.45, 0.65, effects.wetness * 0.6);
+            sprite.custom_size = Some(puddle.size * (0.4 + 0.6 * effects.wetness));
+        }
+    }
+
+    fn random_point(rng: &mut impl Rng, field: Vec2) -> Vec2 {
+        Vec2::new(
+            rng.random_range(-field.x / 2.0..field.x / 2.0),
+            rng.random_range(-field.y / 2.0..field.y / 2.0),
+        )
+    }
+}
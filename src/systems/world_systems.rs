@@ -1,18 +1,25 @@
 use bevy::prelude::*;
 use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::components::{Obstacle, Plant, Puddle, Shelter, SnowCover, TimeOfDay, TimeSkipped, WeatherEffects, Weather, WorldBounds, WorldSeed};
 
 pub struct WorldSystems;
 
+const WORLD_SIZE: Vec2 = Vec2::new(8000.0, 8000.0);
+
 impl WorldSystems {
-    pub fn spawn_world(mut commands: Commands) {
+    pub fn spawn_world(mut commands: Commands, seed: Res<WorldSeed>) {
         // Spawn terrain
-        Self::spawn_terrain(&mut commands);
+        Self::spawn_terrain(&mut commands, &seed);
         
         // Spawn trees
-        Self::spawn_trees(&mut commands);
+        Self::spawn_trees(&mut commands, &mut seed.rng("trees"));
         
         // Spawn houses
-        Self::spawn_houses(&mut commands);
+        Self::spawn_houses(&mut commands, &mut seed.rng("houses"));
+        
+        // The farm's own barn, close enough for the animals to shelter in
+        Self::spawn_barn(&mut commands, -260.0, 230.0);
         
         // Spawn water features
         Self::spawn_water(&mut commands);
@@ -21,32 +28,36 @@ impl WorldSystems {
         Self::spawn_decorations(&mut commands);
     }
 
-    fn spawn_terrain(commands: &mut Commands) {
+    fn spawn_terrain(commands: &mut Commands, seed: &WorldSeed) {
         // Ground - 10x larger
+        let ground_color = Color::srgb(0.4, 0.6, 0.3);
         commands.spawn((
             Sprite {
-                color: Color::srgb(0.4, 0.6, 0.3),
-                custom_size: Some(Vec2::new(8000.0, 8000.0)),
+                color: ground_color,
+                custom_size: Some(WORLD_SIZE),
                 ..default()
             },
             Transform::from_xyz(0.0, 0.0, -10.0),
+            SnowCover { base_color: ground_color },
         ));
 
+        // Nothing may walk or look past the edge of the ground
+        commands.insert_resource(WorldBounds::new(WORLD_SIZE));
+
         // Dense grass coverage with variety
-        Self::spawn_dense_grass(commands);
+        Self::spawn_dense_grass(commands, &mut seed.rng("grass"));
         
         // Various flower patches
-        Self::spawn_flower_fields(commands);
+        Self::spawn_flower_fields(commands, &mut seed.rng("flowers"));
         
         // Small bushes and shrubs
-        Self::spawn_shrubs(commands);
+        Self::spawn_shrubs(commands, &mut seed.rng("shrubs"));
         
         // Mushroom patches
-        Self::spawn_mushrooms(commands);
+        Self::spawn_mushrooms(commands, &mut seed.rng("mushrooms"));
     }
 
-    fn spawn_dense_grass(commands: &mut Commands) {
-        let mut rng = rand::rng();
+    fn spawn_dense_grass(commands: &mut Commands, rng: &mut ChaCha8Rng) {
         
         // Generate dense grass coverage across the entire map
         for x in (-3800..=3800).step_by(80) {
@@ -58,79 +69,40 @@ impl WorldSystems {
                 let pos_y = y as f32 + offset_y;
                 
                 // Different grass types and colors
-                let grass_type = rng.random_range(0_u32..5);
+                let (color, size, z) = match rng.random_range(0_u32..5) {
+                    // Tall dark green grass
+                    0 => (Color::srgb(0.3, 0.6, 0.2), Vec2::new(8.0, 25.0), -9.8),
+                    // Medium green grass clumps
+                    1 => (Color::srgb(0.4, 0.7, 0.3), Vec2::new(15.0, 20.0), -9.7),
+                    // Light green grass
+                    2 => (Color::srgb(0.5, 0.8, 0.4), Vec2::new(12.0, 18.0), -9.6),
+                    // Yellow-tipped grass
+                    3 => (Color::srgb(0.6, 0.7, 0.3), Vec2::new(10.0, 22.0), -9.7),
+                    // Small grass patches
+                    _ => (Color::srgb(0.45, 0.65, 0.35), Vec2::new(20.0, 15.0), -9.5),
+                };
                 
-                match grass_type {
-                    0 => {
-                        // Tall dark green grass
-                        commands.spawn((
-                            Sprite {
-                                color: Color::srgb(0.3, 0.6, 0.2),
-                                custom_size: Some(Vec2::new(8.0, 25.0)),
-                                ..default()
-                            },
-                            Transform::from_xyz(pos_x, pos_y, -9.8),
-                        ));
-                    }
-                    1 => {
-                        // Medium green grass clumps
-                        commands.spawn((
-                            Sprite {
-                                color: Color::srgb(0.4, 0.7, 0.3),
-                                custom_size: Some(Vec2::new(15.0, 20.0)),
-                                ..default()
-                            },
-                            Transform::from_xyz(pos_x, pos_y, -9.7),
-                        ));
-                    }
-                    2 => {
-                        // Light green grass
-                        commands.spawn((
-                            Sprite {
-                                color: Color::srgb(0.5, 0.8, 0.4),
-                                custom_size: Some(Vec2::new(12.0, 18.0)),
-                                ..default()
-                            },
-                            Transform::from_xyz(pos_x, pos_y, -9.6),
-                        ));
-                    }
-                    3 => {
-                        // Yellow-tipped grass
-                        commands.spawn((
-                            Sprite {
-                                color: Color::srgb(0.6, 0.7, 0.3),
-                                custom_size: Some(Vec2::new(10.0, 22.0)),
-                                ..default()
-                            },
-                            Transform::from_xyz(pos_x, pos_y, -9.7),
-                        ));
-                    }
-                    4 => {
-                        // Small grass patches
-                        commands.spawn((
-                            Sprite {
-                                color: Color::srgb(0.45, 0.65, 0.35),
-                                custom_size: Some(Vec2::new(20.0, 15.0)),
-                                ..default()
-                            },
-                            Transform::from_xyz(pos_x, pos_y, -9.5),
-                        ));
-                    }
-                    _ => {} // Default case
-                }
+                commands.spawn((
+                    Sprite {
+                        color,
+                        custom_size: Some(size),
+                        ..default()
+                    },
+                    Transform::from_xyz(pos_x, pos_y, z),
+                    SnowCover { base_color: color },
+                ));
             }
         }
     }
 
-    fn spawn_flower_fields(commands: &mut Commands) {
-        let mut rng = rand::rng();
+    fn spawn_flower_fields(commands: &mut Commands, rng: &mut ChaCha8Rng) {
         
         // Generate flowers completely randomly across the entire map
         for _ in 0..3000 {
             let pos_x = rng.random_range(-3500.0..3500.0);
             let pos_y = rng.random_range(-3500.0..3500.0);
             
-            Self::spawn_random_flower(commands, pos_x, pos_y, &mut rng);
+            Self::spawn_random_flower(commands, pos_x, pos_y, rng);
         }
         
         // Add some small flower clusters for variety
@@ -144,12 +116,12 @@ impl WorldSystems {
                 let pos_x = cluster_center_x + angle.cos() * radius;
                 let pos_y = cluster_center_y + angle.sin() * radius;
                 
-                Self::spawn_random_flower(commands, pos_x, pos_y, &mut rng);
+                Self::spawn_random_flower(commands, pos_x, pos_y, rng);
             }
         }
     }
 
-    fn spawn_random_flower(commands: &mut Commands, x: f32, y: f32, rng: &mut rand::rngs::ThreadRng) {
+    fn spawn_random_flower(commands: &mut Commands, x: f32, y: f32, rng: &mut ChaCha8Rng) {
         let flower_type = rng.random_range(0_u32..8);
         
         match flower_type {
@@ -208,6 +180,11 @@ impl WorldSystems {
                 ..default()
             },
             Transform::from_xyz(x, y + 6.0, -9.3),
+            Plant {
+                hydration: 1.0,
+                base_color: petal_color,
+                wilt_applied: 0.0,
+            },
         ));
 
         // Flower center
@@ -221,8 +198,7 @@ impl WorldSystems {
         ));
     }
 
-    fn spawn_shrubs(commands: &mut Commands) {
-        let mut rng = rand::rng();
+    fn spawn_shrubs(commands: &mut Commands, rng: &mut ChaCha8Rng) {
         
         // Random shrub distribution across the entire map
         for _ in 0..300 {
@@ -320,8 +296,7 @@ impl WorldSystems {
         }
     }
 
-    fn spawn_mushrooms(commands: &mut Commands) {
-        let mut rng = rand::rng();
+    fn spawn_mushrooms(commands: &mut Commands, rng: &mut ChaCha8Rng) {
         
         // Random mushroom distribution across the map
         for _ in 0..150 {
@@ -495,8 +470,7 @@ impl WorldSystems {
         }
     }
 
-    fn spawn_trees(commands: &mut Commands) {
-        let mut rng = rand::rng();
+    fn spawn_trees(commands: &mut Commands, rng: &mut ChaCha8Rng) {
         let mut tree_positions = Vec::new();
         
         // Generate random forest clusters across the map
@@ -517,7 +491,7 @@ impl WorldSystems {
                 if pos_x >= -3400.0 && pos_x <= 3400.0 && pos_y >= -3400.0 && pos_y <= 3400.0 {
                     if Self::is_position_valid_for_tree(pos_x, pos_y, &tree_positions) {
                         tree_positions.push((pos_x, pos_y));
-                        Self::spawn_random_tree(commands, pos_x, pos_y, &mut rng, tree_positions.len());
+                        Self::spawn_random_tree(commands, pos_x, pos_y, rng, tree_positions.len());
                     }
                 }
             }
@@ -532,7 +506,7 @@ impl WorldSystems {
             
             if Self::is_position_valid_for_tree(pos_x, pos_y, &tree_positions) {
                 tree_positions.push((pos_x, pos_y));
-                Self::spawn_random_tree(commands, pos_x, pos_y, &mut rng, tree_positions.len());
+                Self::spawn_random_tree(commands, pos_x, pos_y, rng, tree_positions.len());
             }
         }
         
@@ -550,7 +524,7 @@ impl WorldSystems {
             
             if Self::is_position_valid_for_tree(pos_x, pos_y, &tree_positions) {
                 tree_positions.push((pos_x, pos_y));
-                Self::spawn_random_tree(commands, pos_x, pos_y, &mut rng, tree_positions.len());
+                Self::spawn_random_tree(commands, pos_x, pos_y, rng, tree_positions.len());
             }
         }
     }
@@ -567,7 +541,7 @@ impl WorldSystems {
         true
     }
 
-    fn spawn_random_tree(commands: &mut Commands, x: f32, y: f32, rng: &mut rand::rngs::ThreadRng, tree_index: usize) {
+    fn spawn_random_tree(commands: &mut Commands, x: f32, y: f32, rng: &mut ChaCha8Rng, tree_index: usize) {
         let tree_type = rng.random_range(0..3);
         
         // Use unique Z-values based on tree index to prevent overlap rendering issues
@@ -591,6 +565,7 @@ impl WorldSystems {
                         ..default()
                     },
                     Transform::from_xyz(x, y + 40.0 * size_variation, z_trunk),
+                    Self::trunk_obstacle(trunk_width, trunk_height),
                 ));
                 
                 commands.spawn((
@@ -616,6 +591,7 @@ impl WorldSystems {
                         ..default()
                     },
                     Transform::from_xyz(x, y + 50.0 * size_variation, z_trunk),
+                    Self::trunk_obstacle(trunk_width, trunk_height),
                 ));
                 
                 commands.spawn((
@@ -640,6 +616,7 @@ impl WorldSystems {
                         ..default()
                     },
                     Transform::from_xyz(x, y + 35.0 * size_variation, z_trunk),
+                    Self::trunk_obstacle(trunk_width, trunk_height),
                 ));
                 
                 commands.spawn((
@@ -654,8 +631,12 @@ impl WorldSystems {
         }
     }
 
-    fn spawn_houses(commands: &mut Commands) {
-        let mut rng = rand::rng();
+    // Only the lower half of the trunk blocks, so the player can walk up behind a tree
+    fn trunk_obstacle(trunk_width: f32, trunk_height: f32) -> Obstacle {
+        Obstacle::solid(Vec2::new(trunk_width, trunk_height * 0.5)).with_offset(Vec2::new(0.0, -trunk_height * 0.25))
+    }
+
+    fn spawn_houses(commands: &mut Commands, rng: &mut ChaCha8Rng) {
         
         // Generate random number of houses (4-8 houses)
         let house_count = rng.random_range(4..9);
@@ -688,6 +669,7 @@ impl WorldSystems {
                 ..default()
             },
             Transform::from_xyz(x, y, -6.0),
+            Obstacle::solid(Vec2::new(120.0, 80.0)),
         ));
 
         // House roof
@@ -749,6 +731,8 @@ impl WorldSystems {
                 ..default()
             },
             Transform::from_xyz(x, y, -6.0),
+            Obstacle::solid(Vec2::new(160.0, 100.0)),
+            Shelter,
         ));
 
         // Barn roof (triangular shape simulated with rectangle)
@@ -791,6 +775,7 @@ impl WorldSystems {
                 ..default()
             },
             Transform::from_xyz(100.0, 50.0, -8.0),
+            Obstacle::water(Vec2::new(100.0, 80.0)),
         ));
 
         // Water sparkles/decorations
@@ -809,6 +794,25 @@ impl WorldSystems {
                 Transform::from_xyz(pos.0, pos.1, -7.5),
             ));
         }
+
+        // Puddles around the pond, invisible until it rains
+        let puddles = [
+            (30.0, 10.0, 40.0, 18.0), (170.0, 80.0, 34.0, 16.0), (60.0, 110.0, 28.0, 12.0),
+            (150.0, -5.0, 46.0, 20.0), (20.0, 75.0, 24.0, 12.0),
+        ];
+
+        for (x, y, width, height) in puddles {
+            let size = Vec2::new(width, height);
+            commands.spawn((
+                Sprite {
+                    color: Color::srgba(0.35, 0.45, 0.65, 0.0),
+                    custom_size: Some(size),
+                    ..default()
+                },
+                Transform::from_xyz(x, y, -8.1),
+                Puddle { size },
+            ));
+        }
     }
 
     fn spawn_decorations(commands: &mut Commands) {
@@ -829,6 +833,7 @@ impl WorldSystems {
                     ..default()
                 },
                 Transform::from_xyz(pos.0, pos.1, -7.0),
+                Obstacle::solid(Vec2::new(20.0, 15.0)),
             ));
         }
 
@@ -847,11 +852,48 @@ impl WorldSystems {
                     ..default()
                 },
                 Transform::from_xyz(pos.0, pos.1, -7.0),
+                Obstacle::solid(Vec2::new(15.0, 25.0)),
             ));
         }
     }
 
     
+    // Rain waters every plant; in dry weather they slowly dry out and wilt, faster in the heat
+    pub fn water_plants(
+        time: Res<Time>,
+        time_of_day: Res<TimeOfDay>,
+        mut skipped_events: EventReader<TimeSkipped>,
+        weather: Res<Weather>,
+        effects: Res<WeatherEffects>,
+        mut query: Query<(&mut Sprite, &mut Plant)>,
+    ) {
+        let game_hours = time_of_day.elapsed_minutes(&time, &mut skipped_events) / 60.0;
+        if game_hours == 0.0 {
+            return;
+        }
+        
+        let raining = !effects.falling_snow && effects.intensity > 0.1;
+        let change = if raining {
+            game_hours * effects.intensity
+        } else {
+            let heat = 1.0 + (weather.temperature - 25.0).max(0.0) / 10.0;
+            -game_hours / 48.0 * heat
+        };
+        
+        let withered = LinearRgba::rgb(0.45, 0.35, 0.2);
+        for (mut sprite, mut plant) in query.iter_mut() {
+            plant.hydration = (plant.hydration + change).clamp(0.0, 1.0);
+            
+            // Plants only start to wilt once they are fairly dry
+            let wilt = ((0.3 - plant.hydration) / 0.3).clamp(0.0, 1.0);
+            if (wilt - plant.wilt_applied).abs() < 0.05 && !(wilt == 0.0 && plant.wilt_applied != 0.0) {
+                continue;
+            }
+            plant.wilt_applied = wilt;
+            sprite.color = Color::LinearRgba(LinearRgba::from(plant.base_color).mix(&withered, wilt * 0.7));
+        }
+    }
+    
     pub fn animate_water(
         time: Res<Time>,
         mut query: Query<&mut Transform, With<WaterDecoration>>,